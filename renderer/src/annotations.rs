@@ -0,0 +1,133 @@
+use crate::data::CollectedData;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// One entry from an `--annotations` file: a caption attached either to a
+/// commit (matched by full/abbreviated SHA or tag name) or to an absolute
+/// point in time. Exactly one of `commit`/`time` is expected to be set; if
+/// both are, `commit` takes precedence.
+#[derive(Debug, Deserialize)]
+pub struct Annotation {
+    #[serde(default)]
+    pub commit: Option<String>,
+    #[serde(default)]
+    pub time: Option<DateTime<Utc>>,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct AnnotationFile {
+    #[serde(default)]
+    annotations: Vec<Annotation>,
+}
+
+/// Load an annotations file. JSON only — this repo already takes its input
+/// data as JSON (see `data::load_data`), so annotations follow the same
+/// convention rather than adding a TOML parser dependency for one file.
+pub fn load_annotations(path: &Path) -> Result<Vec<Annotation>, Box<dyn std::error::Error>> {
+    let contents = fs::read_to_string(path)?;
+    let file: AnnotationFile = serde_json::from_str(&contents)?;
+    Ok(file.annotations)
+}
+
+/// An `Annotation` resolved to the frame it first becomes visible at, plus
+/// how long it fades in, holds, and fades out, all in frames.
+#[derive(Debug, Clone)]
+pub struct ResolvedAnnotation {
+    pub frame_index: u32,
+    pub fade_frames: u32,
+    pub hold_frames: u32,
+    pub text: String,
+}
+
+impl ResolvedAnnotation {
+    /// Opacity in `[0, 1]` this annotation should render at for `frame_index`,
+    /// or `None` if it isn't active at that frame. Fades in over
+    /// `fade_frames`, holds at full opacity for `hold_frames`, then fades
+    /// back out over `fade_frames`.
+    pub fn alpha_at(&self, frame_index: u32) -> Option<f32> {
+        if frame_index < self.frame_index {
+            return None;
+        }
+        let rel = frame_index - self.frame_index;
+        if self.fade_frames == 0 {
+            return if rel < self.hold_frames { Some(1.0) } else { None };
+        }
+        if rel < self.fade_frames {
+            Some(rel as f32 / self.fade_frames as f32)
+        } else if rel < self.fade_frames + self.hold_frames {
+            Some(1.0)
+        } else if rel < 2 * self.fade_frames + self.hold_frames {
+            let fade_out_rel = rel - self.fade_frames - self.hold_frames;
+            Some(1.0 - fade_out_rel as f32 / self.fade_frames as f32)
+        } else {
+            None
+        }
+    }
+}
+
+/// Map a commit's position in `data.commits` to the frame index where it
+/// first becomes visible, inverting the same `visible_count` progress
+/// formula `render_frame`'s callers use to decide what's on screen each
+/// frame.
+fn frame_for_commit_index(commit_index: usize, num_commits: usize, total_frames: u32) -> u32 {
+    if num_commits == 0 || total_frames == 0 {
+        return 0;
+    }
+    // Frame f shows ceil((f + 1) * num_commits / total_frames) commits, so
+    // commit k is first on screen at the smallest f with
+    // (f + 1) * num_commits / total_frames > k, i.e. floor(k * total_frames / num_commits).
+    let idx = (commit_index as f32 * total_frames as f32 / num_commits as f32).floor() as u32;
+    idx.min(total_frames - 1)
+}
+
+fn find_commit_index(data: &CollectedData, target: &str) -> Option<usize> {
+    data.commits
+        .iter()
+        .position(|c| c.sha == target || c.sha.starts_with(target) || c.tags.iter().any(|t| t == target))
+}
+
+fn find_commit_index_by_time(data: &CollectedData, time: DateTime<Utc>) -> Option<usize> {
+    data.commits
+        .iter()
+        .position(|c| c.timestamp >= time)
+        .or(if data.commits.is_empty() { None } else { Some(data.commits.len() - 1) })
+}
+
+/// Resolve raw `Annotation`s against `data`'s commit order into frame
+/// indices, dropping any whose `commit`/`time` target can't be found.
+/// `fade_frames` matches `--fade-frames` (the same knob used for the
+/// intro/outro title cards); `hold_secs`/`fps` set how long each card stays
+/// fully visible once reached.
+pub fn resolve_annotations(
+    raw: &[Annotation],
+    data: &CollectedData,
+    num_commits: usize,
+    total_frames: u32,
+    fade_frames: u32,
+    hold_secs: f32,
+    fps: u32,
+) -> Vec<ResolvedAnnotation> {
+    let hold_frames = ((hold_secs * fps as f32).round() as u32).max(1);
+
+    raw.iter()
+        .filter_map(|a| {
+            let commit_index = if let Some(ref commit) = a.commit {
+                find_commit_index(data, commit)
+            } else if let Some(time) = a.time {
+                find_commit_index_by_time(data, time)
+            } else {
+                None
+            }?;
+
+            Some(ResolvedAnnotation {
+                frame_index: frame_for_commit_index(commit_index, num_commits, total_frames),
+                fade_frames,
+                hold_frames,
+                text: a.text.clone(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,180 @@
+//! GitHub-style calendar heatmap: commits bucketed into a day x week grid,
+//! each day colored by commit-count intensity — a dense year-at-a-glance view
+//! the lane-based timeline layout can't show.
+
+use crate::data::CollectedData;
+use crate::text::TextRenderer;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tiny_skia::{Color, Paint, PathBuilder, Pixmap, Transform};
+
+/// Color ramp for the heatmap cells, light (no commits) to dark (busiest day).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeatmapColors {
+    Green,
+    Blue,
+    Halloween,
+}
+
+impl HeatmapColors {
+    /// Parse a `--calendar-heatmap-colors` CLI value, falling back to Green
+    /// (GitHub's own contribution-graph ramp) for anything unrecognized.
+    pub fn from_str_or_default(s: &str) -> HeatmapColors {
+        match s {
+            "blue" => HeatmapColors::Blue,
+            "halloween" => HeatmapColors::Halloween,
+            _ => HeatmapColors::Green,
+        }
+    }
+
+    fn ramp(self) -> [Color; 5] {
+        match self {
+            HeatmapColors::Green => [
+                Color::from_rgba8(235, 237, 240, 255),
+                Color::from_rgba8(155, 233, 168, 255),
+                Color::from_rgba8(64, 196, 99, 255),
+                Color::from_rgba8(48, 161, 78, 255),
+                Color::from_rgba8(33, 110, 57, 255),
+            ],
+            HeatmapColors::Blue => [
+                Color::from_rgba8(235, 237, 240, 255),
+                Color::from_rgba8(158, 202, 225, 255),
+                Color::from_rgba8(107, 174, 214, 255),
+                Color::from_rgba8(49, 130, 189, 255),
+                Color::from_rgba8(8, 81, 156, 255),
+            ],
+            HeatmapColors::Halloween => [
+                Color::from_rgba8(235, 237, 240, 255),
+                Color::from_rgba8(255, 238, 170, 255),
+                Color::from_rgba8(255, 184, 77, 255),
+                Color::from_rgba8(247, 105, 2, 255),
+                Color::from_rgba8(43, 14, 68, 255),
+            ],
+        }
+    }
+}
+
+/// Bucket a day's commit count into one of 5 intensity levels, thresholds
+/// matching GitHub's own contribution graph (0, 1-3, 4-6, 7-9, 10+).
+fn intensity_level(count: u32) -> usize {
+    match count {
+        0 => 0,
+        1..=3 => 1,
+        4..=6 => 2,
+        7..=9 => 3,
+        _ => 4,
+    }
+}
+
+pub(crate) fn month_name(m: u32) -> &'static str {
+    const NAMES: [&str; 12] =
+        ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    NAMES[(m as usize).saturating_sub(1).min(11)]
+}
+
+pub(crate) fn fill_rounded_rect(pixmap: &mut Pixmap, x: f32, y: f32, w: f32, h: f32, r: f32, paint: &Paint) {
+    let r = r.min(w / 2.0).min(h / 2.0);
+    let mut pb = PathBuilder::new();
+    pb.move_to(x + r, y);
+    pb.line_to(x + w - r, y);
+    pb.cubic_to(x + w, y, x + w, y, x + w, y + r);
+    pb.line_to(x + w, y + h - r);
+    pb.cubic_to(x + w, y + h, x + w, y + h, x + w - r, y + h);
+    pb.line_to(x + r, y + h);
+    pb.cubic_to(x, y + h, x, y + h, x, y + h - r);
+    pb.line_to(x, y + r);
+    pb.cubic_to(x, y, x, y, x + r, y);
+    pb.close();
+    if let Some(path) = pb.finish() {
+        pixmap.fill_path(&path, paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+    }
+}
+
+/// Render `data.commits` as a GitHub-style calendar heatmap PNG: weeks as
+/// columns (Sunday-start, matching GitHub's own grid), weekdays as rows,
+/// each cell a rounded square colored by that day's commit-count intensity.
+pub fn render_calendar_heatmap(
+    data: &CollectedData,
+    output_path: &Path,
+    colors: HeatmapColors,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut by_day: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+    for commit in &data.commits {
+        *by_day.entry(commit.timestamp.date_naive()).or_insert(0) += 1;
+    }
+
+    let text = TextRenderer::new();
+    let white = Color::from_rgba8(255, 255, 255, 255);
+    let dim = Color::from_rgba8(140, 140, 140, 255);
+    let bg = Color::from_rgba8(25, 25, 30, 255);
+
+    let (Some(&first), Some(&last)) = (by_day.keys().next(), by_day.keys().next_back()) else {
+        let mut pixmap = Pixmap::new(800, 200).unwrap();
+        pixmap.fill(bg);
+        text.draw_text(&mut pixmap, "No commits to chart", 40.0, 50.0, 20.0, white);
+        pixmap.save_png(output_path)?;
+        eprintln!("  Wrote {:?}", output_path);
+        return Ok(());
+    };
+
+    // Grid starts on the Sunday on/before the first commit's date, GitHub's
+    // own convention, so week columns line up with calendar weeks.
+    let grid_start = first - Duration::days(i64::from(first.weekday().num_days_from_sunday()));
+    let num_weeks = (last - grid_start).num_days() / 7 + 1;
+
+    let cell = 14.0f32;
+    let gap = 3.0f32;
+    let stride = cell + gap;
+    let margin_left = 50.0f32;
+    let margin_top = 60.0f32;
+
+    let width = (margin_left + num_weeks as f32 * stride + 40.0).max(400.0) as u32;
+    let height = (margin_top + 7.0 * stride + 40.0) as u32;
+
+    let mut pixmap = Pixmap::new(width, height).unwrap();
+    pixmap.fill(bg);
+
+    text.draw_text(&mut pixmap, "Commit Calendar Heatmap", 40.0, 30.0, 20.0, white);
+
+    let weekday_labels = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    for (row, label) in weekday_labels.iter().enumerate() {
+        if row % 2 == 1 {
+            text.draw_text(&mut pixmap, label, 10.0, margin_top + row as f32 * stride + cell - 2.0, 10.0, dim);
+        }
+    }
+
+    // Month labels along the top, printed once per month at the column of
+    // that month's first visible week.
+    let mut last_month = None;
+    for week in 0..num_weeks {
+        let week_start = grid_start + Duration::days(week * 7);
+        let month = week_start.month();
+        if last_month != Some(month) {
+            last_month = Some(month);
+            let x = margin_left + week as f32 * stride;
+            text.draw_text(&mut pixmap, month_name(month), x, margin_top - 8.0, 11.0, dim);
+        }
+    }
+
+    let ramp = colors.ramp();
+    for (&date, &count) in &by_day {
+        if date < grid_start {
+            continue;
+        }
+        let days_since_start = (date - grid_start).num_days();
+        let col = days_since_start / 7;
+        let row = i64::from(date.weekday().num_days_from_sunday());
+        let x = margin_left + col as f32 * stride;
+        let y = margin_top + row as f32 * stride;
+
+        let level = intensity_level(count);
+        let mut paint = Paint::default();
+        paint.set_color(ramp[level]);
+        fill_rounded_rect(&mut pixmap, x, y, cell, cell, 2.0, &paint);
+    }
+
+    pixmap.save_png(output_path)?;
+    eprintln!("  Wrote {:?}", output_path);
+    Ok(())
+}
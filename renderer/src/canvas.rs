@@ -0,0 +1,371 @@
+use crate::gif::dither_frame;
+use crate::indexed_png;
+use crate::palette::Palette;
+use crate::text::TextRenderer;
+use std::path::Path;
+use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Stroke, Transform};
+
+/// Output format for change flow charts: fixed-resolution raster PNG, or
+/// scalable vector SVG for crisp embedding in docs/READMEs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Png,
+    Svg,
+}
+
+impl OutputFormat {
+    /// Parse a `--chart-format` CLI value, falling back to PNG for anything
+    /// unrecognized.
+    pub fn from_str_or_default(s: &str) -> OutputFormat {
+        match s {
+            "svg" => OutputFormat::Svg,
+            _ => OutputFormat::Png,
+        }
+    }
+}
+
+/// Drawing primitives shared by every change flow chart, abstracted over a
+/// raster (`PixmapCanvas`) or vector (`SvgCanvas`) backend — mirrors how
+/// plotters exposes one drawing-area API over `BitMapBackend`/`SVGBackend`.
+pub trait Canvas {
+    fn fill_background(&mut self, color: Color);
+    fn rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color);
+    fn rect_alpha(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color, alpha: f32);
+    fn hatched_rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color);
+    fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, color: Color, width: f32);
+    fn dashed_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, color: Color, width: f32, dash_len: f32);
+    fn circle(&mut self, cx: f32, cy: f32, r: f32, color: Color);
+    fn arc_filled(&mut self, cx: f32, cy: f32, r_inner: f32, r_outer: f32, start_angle: f32, sweep: f32, color: Color);
+    fn text(&mut self, s: &str, x: f32, y: f32, size: f32, color: Color);
+    fn measure_text(&self, s: &str, size: f32) -> f32;
+    /// Write the chart to `dir/{stem}.{ext}`, appending the backend's own
+    /// extension, and log the path the same way every chart already does.
+    fn save(&self, dir: &Path, stem: &str) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+pub fn new_canvas(format: OutputFormat, width: u32, height: u32, indexed_png: bool) -> Box<dyn Canvas> {
+    match format {
+        OutputFormat::Png => Box::new(PixmapCanvas::new(width, height, indexed_png)),
+        OutputFormat::Svg => Box::new(SvgCanvas::new(width, height)),
+    }
+}
+
+// ============================================================
+// Raster backend (tiny-skia)
+// ============================================================
+
+pub struct PixmapCanvas {
+    pixmap: Pixmap,
+    text: TextRenderer,
+    /// When set, `save` quantizes to an 8-bit indexed palette (median-cut +
+    /// k-means refinement, same `Palette` the GIF encoder uses) instead of
+    /// writing full 32-bit RGBA. The indexed file holds one uncompressed
+    /// palette-index byte per pixel (see `indexed_png`); it is not deflate-
+    /// compressed.
+    indexed: bool,
+}
+
+impl PixmapCanvas {
+    pub fn new(width: u32, height: u32, indexed: bool) -> PixmapCanvas {
+        PixmapCanvas {
+            pixmap: Pixmap::new(width, height).unwrap(),
+            text: TextRenderer::new(),
+            indexed,
+        }
+    }
+}
+
+impl Canvas for PixmapCanvas {
+    fn fill_background(&mut self, color: Color) {
+        self.pixmap.fill(color);
+    }
+
+    fn rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        let mut paint = Paint::default();
+        paint.set_color(color);
+        let mut pb = PathBuilder::new();
+        pb.move_to(x, y);
+        pb.line_to(x + w, y);
+        pb.line_to(x + w, y + h);
+        pb.line_to(x, y + h);
+        pb.close();
+        if let Some(path) = pb.finish() {
+            self.pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+        }
+    }
+
+    fn rect_alpha(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color, alpha: f32) {
+        if let Some(c) = Color::from_rgba(color.red(), color.green(), color.blue(), alpha) {
+            self.rect(x, y, w, h, c);
+        }
+    }
+
+    fn hatched_rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        self.rect(x, y, w, h, color);
+        let hatch_color = Color::from_rgba8(0, 0, 0, 120);
+        let spacing = 6.0;
+        let mut offset = 0.0;
+        while offset < w + h {
+            let x1 = x + (offset - h).max(0.0);
+            let y1 = y + (h - (offset - (offset - h).max(0.0))).max(0.0);
+            let x2 = x + offset.min(w);
+            let y2 = y + (offset - offset.min(w)).max(0.0);
+            self.line(x1, y1, x2, y2, hatch_color, 1.0);
+            offset += spacing;
+        }
+    }
+
+    fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, color: Color, width: f32) {
+        let mut paint = Paint::default();
+        paint.set_color(color);
+        paint.anti_alias = true;
+        let stroke = Stroke { width, ..Stroke::default() };
+        let mut pb = PathBuilder::new();
+        pb.move_to(x1, y1);
+        pb.line_to(x2, y2);
+        if let Some(path) = pb.finish() {
+            self.pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+
+    fn dashed_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, color: Color, width: f32, dash_len: f32) {
+        let dx = x2 - x1;
+        let dy = y2 - y1;
+        let len = (dx * dx + dy * dy).sqrt();
+        if len < 1.0 {
+            return;
+        }
+        let nx = dx / len;
+        let ny = dy / len;
+        let mut pos = 0.0;
+        let mut drawing = true;
+        while pos < len {
+            let seg = dash_len.min(len - pos);
+            if drawing {
+                let sx = x1 + nx * pos;
+                let sy = y1 + ny * pos;
+                let ex = x1 + nx * (pos + seg);
+                let ey = y1 + ny * (pos + seg);
+                self.line(sx, sy, ex, ey, color, width);
+            }
+            pos += seg;
+            drawing = !drawing;
+        }
+    }
+
+    fn circle(&mut self, cx: f32, cy: f32, r: f32, color: Color) {
+        let mut paint = Paint::default();
+        paint.set_color(color);
+        paint.anti_alias = true;
+        let mut pb = PathBuilder::new();
+        pb.push_circle(cx, cy, r);
+        if let Some(path) = pb.finish() {
+            self.pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+        }
+    }
+
+    fn arc_filled(&mut self, cx: f32, cy: f32, r_inner: f32, r_outer: f32, start_angle: f32, sweep: f32, color: Color) {
+        if sweep.abs() < 0.001 {
+            return;
+        }
+        let steps = ((sweep.abs() * 50.0) as usize).max(4);
+        let step_angle = sweep / steps as f32;
+
+        let mut paint = Paint::default();
+        paint.set_color(color);
+        paint.anti_alias = true;
+
+        let mut pb = PathBuilder::new();
+        let a0 = start_angle;
+        pb.move_to(cx + a0.cos() * r_outer, cy + a0.sin() * r_outer);
+        for i in 1..=steps {
+            let a = a0 + i as f32 * step_angle;
+            pb.line_to(cx + a.cos() * r_outer, cy + a.sin() * r_outer);
+        }
+        for i in (0..=steps).rev() {
+            let a = a0 + i as f32 * step_angle;
+            pb.line_to(cx + a.cos() * r_inner, cy + a.sin() * r_inner);
+        }
+        pb.close();
+        if let Some(path) = pb.finish() {
+            self.pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+        }
+    }
+
+    fn text(&mut self, s: &str, x: f32, y: f32, size: f32, color: Color) {
+        self.text.draw_text(&mut self.pixmap, s, x, y, size, color);
+    }
+
+    fn measure_text(&self, s: &str, size: f32) -> f32 {
+        self.text.measure_text(s, size)
+    }
+
+    fn save(&self, dir: &Path, stem: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let path = dir.join(format!("{stem}.png"));
+        self.write_png(&path)
+    }
+}
+
+impl PixmapCanvas {
+    /// Raw premultiplied RGBA8 pixels, row-major — for callers that need to
+    /// quantize/dither frames themselves (e.g. assembling an animated GIF)
+    /// rather than writing a single PNG through `Canvas::save`.
+    pub(crate) fn rgba(&self) -> &[u8] {
+        self.pixmap.data()
+    }
+
+    fn write_png(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        if self.indexed {
+            let rgba = self.pixmap.data();
+            let samples: Vec<[u8; 3]> = rgba.chunks_exact(4).map(|px| [px[0], px[1], px[2]]).collect();
+            let mut palette = Palette::build(samples.iter().copied(), 256);
+            palette.refine_kmeans(&samples, 4);
+            let indices = dither_frame(rgba, self.pixmap.width(), self.pixmap.height(), &palette);
+            indexed_png::write(&path, self.pixmap.width(), self.pixmap.height(), &palette.colors, &indices)?;
+        } else {
+            self.pixmap.save_png(&path)?;
+        }
+        eprintln!("  Wrote {:?}", path);
+        Ok(())
+    }
+}
+
+// ============================================================
+// Vector backend (SVG)
+// ============================================================
+
+pub struct SvgCanvas {
+    width: u32,
+    height: u32,
+    elements: Vec<String>,
+    text: TextRenderer,
+}
+
+fn svg_color(color: Color) -> String {
+    format!(
+        "rgb({},{},{})",
+        (color.red() * 255.0).round() as u8,
+        (color.green() * 255.0).round() as u8,
+        (color.blue() * 255.0).round() as u8,
+    )
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+impl SvgCanvas {
+    pub fn new(width: u32, height: u32) -> SvgCanvas {
+        SvgCanvas { width, height, elements: Vec::new(), text: TextRenderer::new() }
+    }
+}
+
+impl Canvas for SvgCanvas {
+    fn fill_background(&mut self, color: Color) {
+        self.elements.push(format!(
+            r#"<rect x="0" y="0" width="{}" height="{}" fill="{}"/>"#,
+            self.width, self.height, svg_color(color)
+        ));
+    }
+
+    fn rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        self.rect_alpha(x, y, w, h, color, color.alpha());
+    }
+
+    fn rect_alpha(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color, alpha: f32) {
+        self.elements.push(format!(
+            r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="{}" fill-opacity="{:.3}"/>"#,
+            x, y, w, h, svg_color(color), alpha
+        ));
+    }
+
+    fn hatched_rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        self.rect(x, y, w, h, color);
+        let hatch_color = Color::from_rgba8(0, 0, 0, 120);
+        let spacing = 6.0;
+        let mut offset = 0.0;
+        while offset < w + h {
+            let x1 = x + (offset - h).max(0.0);
+            let y1 = y + (h - (offset - (offset - h).max(0.0))).max(0.0);
+            let x2 = x + offset.min(w);
+            let y2 = y + (offset - offset.min(w)).max(0.0);
+            self.line(x1, y1, x2, y2, hatch_color, 1.0);
+            offset += spacing;
+        }
+    }
+
+    fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, color: Color, width: f32) {
+        self.elements.push(format!(
+            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-opacity="{:.3}" stroke-width="{:.2}"/>"#,
+            x1, y1, x2, y2, svg_color(color), color.alpha(), width
+        ));
+    }
+
+    fn dashed_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, color: Color, width: f32, dash_len: f32) {
+        self.elements.push(format!(
+            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-opacity="{:.3}" stroke-width="{:.2}" stroke-dasharray="{:.1},{:.1}"/>"#,
+            x1, y1, x2, y2, svg_color(color), color.alpha(), width, dash_len, dash_len
+        ));
+    }
+
+    fn circle(&mut self, cx: f32, cy: f32, r: f32, color: Color) {
+        self.elements.push(format!(
+            r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}" fill-opacity="{:.3}"/>"#,
+            cx, cy, r, svg_color(color), color.alpha()
+        ));
+    }
+
+    fn arc_filled(&mut self, cx: f32, cy: f32, r_inner: f32, r_outer: f32, start_angle: f32, sweep: f32, color: Color) {
+        if sweep.abs() < 0.001 {
+            return;
+        }
+        let a0 = start_angle;
+        let a1 = start_angle + sweep;
+        let large_arc = if sweep.abs() > std::f32::consts::PI { 1 } else { 0 };
+        let sweep_flag = if sweep > 0.0 { 1 } else { 0 };
+
+        let outer_start = (cx + a0.cos() * r_outer, cy + a0.sin() * r_outer);
+        let outer_end = (cx + a1.cos() * r_outer, cy + a1.sin() * r_outer);
+        let inner_end = (cx + a1.cos() * r_inner, cy + a1.sin() * r_inner);
+        let inner_start = (cx + a0.cos() * r_inner, cy + a0.sin() * r_inner);
+
+        let d = format!(
+            "M {:.2} {:.2} A {:.2} {:.2} 0 {} {} {:.2} {:.2} L {:.2} {:.2} A {:.2} {:.2} 0 {} {} {:.2} {:.2} Z",
+            outer_start.0, outer_start.1,
+            r_outer, r_outer, large_arc, sweep_flag, outer_end.0, outer_end.1,
+            inner_end.0, inner_end.1,
+            r_inner, r_inner, large_arc, 1 - sweep_flag, inner_start.0, inner_start.1,
+        );
+        self.elements.push(format!(
+            r#"<path d="{}" fill="{}" fill-opacity="{:.3}"/>"#,
+            d, svg_color(color), color.alpha()
+        ));
+    }
+
+    fn text(&mut self, s: &str, x: f32, y: f32, size: f32, color: Color) {
+        self.elements.push(format!(
+            r#"<text x="{:.2}" y="{:.2}" font-family="monospace" font-size="{:.1}" fill="{}" fill-opacity="{:.3}">{}</text>"#,
+            x, y, size, svg_color(color), color.alpha(), escape_xml(s)
+        ));
+    }
+
+    fn measure_text(&self, s: &str, size: f32) -> f32 {
+        self.text.measure_text(s, size)
+    }
+
+    fn save(&self, dir: &Path, stem: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let path = dir.join(format!("{stem}.svg"));
+        let body = self.elements.join("\n");
+        let svg = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">
+{}
+</svg>
+"#,
+            self.width, self.height, self.width, self.height, body
+        );
+        std::fs::write(&path, svg)?;
+        eprintln!("  Wrote {:?}", path);
+        Ok(())
+    }
+}
@@ -1,160 +1,333 @@
-use crate::data::ChangeFlowMetrics;
-use crate::text::TextRenderer;
+use crate::canvas::{self, Canvas, OutputFormat, PixmapCanvas};
+use crate::data::{ChangeFlowMetrics, ReleaseInterval};
+use crate::gif::{dither_frame, GifEncoder};
+use crate::palette::Palette;
+use crate::timing::Timeline;
+use chrono::{Datelike, NaiveDate};
 use rayon::prelude::*;
 use std::path::Path;
-use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Stroke, Transform};
+use tiny_skia::Color;
 
 const WIDTH: u32 = 1920;
 const HEIGHT: u32 = 1080;
-fn bg() -> Color {
-    Color::from_rgba8(18, 18, 24, 255)
+
+/// Theming for every change flow chart. `GreenRed` is the original palette;
+/// `Viridis` and `ColorblindSafe` stay legible for red-green color vision
+/// deficiency, the most common form of colorblindness.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorScheme {
+    GreenRed,
+    Viridis,
+    Blues,
+    ColorblindSafe,
+}
+
+impl ColorScheme {
+    /// Parse a `--color-scheme` CLI value, falling back to the original
+    /// green/red theme for anything unrecognized.
+    pub fn from_str_or_default(s: &str) -> ColorScheme {
+        match s {
+            "viridis" => ColorScheme::Viridis,
+            "blues" => ColorScheme::Blues,
+            "colorblind_safe" | "colorblind-safe" => ColorScheme::ColorblindSafe,
+            _ => ColorScheme::GreenRed,
+        }
+    }
 }
 
-fn white() -> Color {
+/// Okabe-Ito colorblind-safe palette (8 colors, distinguishable under all
+/// common forms of color vision deficiency), cycled by category index.
+const COLORBLIND_SAFE_COLORS: [[u8; 3]; 8] = [
+    [230, 159, 0],   // orange
+    [86, 180, 233],  // sky blue
+    [0, 158, 115],   // bluish green
+    [240, 228, 66],  // yellow
+    [0, 114, 178],   // blue
+    [213, 94, 0],    // vermillion
+    [204, 121, 167], // reddish purple
+    [0, 0, 0],       // black
+];
+
+/// Viridis control stops (perceptually-uniform, approximated with 9 points),
+/// interpolated linearly between neighbors for any t in 0.0..=1.0.
+const VIRIDIS_STOPS: [(f32, [u8; 3]); 9] = [
+    (0.00, [68, 1, 84]),
+    (0.13, [72, 40, 120]),
+    (0.25, [62, 74, 137]),
+    (0.38, [49, 104, 142]),
+    (0.50, [38, 130, 142]),
+    (0.63, [31, 158, 137]),
+    (0.75, [53, 183, 121]),
+    (0.88, [109, 205, 89]),
+    (1.00, [253, 231, 37]),
+];
+
+fn lerp_stops(stops: &[(f32, [u8; 3])], t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    for w in stops.windows(2) {
+        let (t0, c0) = w[0];
+        let (t1, c1) = w[1];
+        if t <= t1 {
+            let frac = if t1 > t0 { (t - t0) / (t1 - t0) } else { 0.0 };
+            let r = (c0[0] as f32 + (c1[0] as f32 - c0[0] as f32) * frac) as u8;
+            let g = (c0[1] as f32 + (c1[1] as f32 - c0[1] as f32) * frac) as u8;
+            let b = (c0[2] as f32 + (c1[2] as f32 - c0[2] as f32) * frac) as u8;
+            return Color::from_rgba8(r, g, b, 255);
+        }
+    }
+    let last = stops[stops.len() - 1].1;
+    Color::from_rgba8(last[0], last[1], last[2], 255)
+}
+
+pub(crate) fn bg(scheme: ColorScheme) -> Color {
+    match scheme {
+        ColorScheme::Blues => Color::from_rgba8(10, 16, 28, 255),
+        _ => Color::from_rgba8(18, 18, 24, 255),
+    }
+}
+
+pub(crate) fn white(_scheme: ColorScheme) -> Color {
     Color::from_rgba8(230, 230, 240, 255)
 }
-fn light() -> Color {
+pub(crate) fn light(_scheme: ColorScheme) -> Color {
     Color::from_rgba8(190, 190, 200, 255)
 }
-fn dim() -> Color {
+pub(crate) fn dim(_scheme: ColorScheme) -> Color {
     Color::from_rgba8(130, 130, 145, 255)
 }
 
-fn category_color(category: &str) -> Color {
+pub(crate) fn category_color(scheme: ColorScheme, category: &str) -> Color {
+    match scheme {
+        ColorScheme::GreenRed => match category {
+            "feature" => Color::from_rgba8(66, 165, 245, 255),
+            "bugfix" => Color::from_rgba8(239, 83, 80, 255),
+            "release" => Color::from_rgba8(255, 215, 0, 255),
+            "refactor" => Color::from_rgba8(186, 104, 200, 255),
+            "docs" => Color::from_rgba8(129, 199, 132, 255),
+            "ci" => Color::from_rgba8(77, 208, 225, 255),
+            "test" => Color::from_rgba8(255, 167, 38, 255),
+            "merge" => Color::from_rgba8(255, 200, 60, 200),
+            "squash" => Color::from_rgba8(255, 183, 77, 255),
+            "conflict" => Color::from_rgba8(244, 67, 54, 255),
+            _ => Color::from_rgba8(158, 158, 158, 255),
+        },
+        ColorScheme::ColorblindSafe => {
+            let idx = category_index(category) % COLORBLIND_SAFE_COLORS.len();
+            let c = COLORBLIND_SAFE_COLORS[idx];
+            Color::from_rgba8(c[0], c[1], c[2], 255)
+        }
+        ColorScheme::Viridis => {
+            let idx = category_index(category);
+            let t = idx as f32 / 9.0;
+            lerp_stops(&VIRIDIS_STOPS, t)
+        }
+        ColorScheme::Blues => {
+            let idx = category_index(category);
+            let t = idx as f32 / 9.0;
+            lerp_stops(&BLUES_STOPS, t)
+        }
+    }
+}
+
+/// Stable index per category name, used to spread categories evenly across
+/// a gradient-based palette (Viridis, Blues) rather than hand-picking hues.
+fn category_index(category: &str) -> usize {
     match category {
-        "feature" => Color::from_rgba8(66, 165, 245, 255),
-        "bugfix" => Color::from_rgba8(239, 83, 80, 255),
-        "release" => Color::from_rgba8(255, 215, 0, 255),
-        "refactor" => Color::from_rgba8(186, 104, 200, 255),
-        "docs" => Color::from_rgba8(129, 199, 132, 255),
-        "ci" => Color::from_rgba8(77, 208, 225, 255),
-        "test" => Color::from_rgba8(255, 167, 38, 255),
-        "merge" => Color::from_rgba8(255, 200, 60, 200),
-        "squash" => Color::from_rgba8(255, 183, 77, 255),
-        "conflict" => Color::from_rgba8(244, 67, 54, 255),
-        _ => Color::from_rgba8(158, 158, 158, 255),
-    }
-}
-
-/// Green→yellow→red gradient. t: 0.0=green, 0.5=yellow, 1.0=red
-fn heat_color(t: f32) -> Color {
-    let t = t.clamp(0.0, 1.0);
-    let r;
-    let g;
-    if t < 0.5 {
-        let s = t * 2.0;
-        r = (s * 255.0) as u8;
-        g = 255;
-    } else {
-        let s = (t - 0.5) * 2.0;
-        r = 255;
-        g = ((1.0 - s) * 255.0) as u8;
+        "feature" => 0,
+        "bugfix" => 1,
+        "release" => 2,
+        "refactor" => 3,
+        "docs" => 4,
+        "ci" => 5,
+        "test" => 6,
+        "merge" => 7,
+        "squash" => 8,
+        "conflict" => 1,
+        _ => 4,
     }
-    Color::from_rgba8(r, g, 40, 255)
 }
 
-fn magenta() -> Color {
-    Color::from_rgba8(255, 0, 200, 255)
+/// Sequential light-to-dark blue control stops for the `Blues` scheme.
+const BLUES_STOPS: [(f32, [u8; 3]); 5] = [
+    (0.00, [222, 235, 247]),
+    (0.25, [158, 202, 225]),
+    (0.50, [66, 146, 198]),
+    (0.75, [33, 102, 172]),
+    (1.00, [8, 48, 107]),
+];
+
+/// Gradient used for "how bad is this" scales (release/merge latency, branch
+/// lifespan). `GreenRed` is green→yellow→red; the others stay legible for
+/// colorblind viewers.
+pub(crate) fn heat_color(scheme: ColorScheme, t: f32) -> Color {
+    match scheme {
+        ColorScheme::GreenRed => {
+            let t = t.clamp(0.0, 1.0);
+            let r;
+            let g;
+            if t < 0.5 {
+                let s = t * 2.0;
+                r = (s * 255.0) as u8;
+                g = 255;
+            } else {
+                let s = (t - 0.5) * 2.0;
+                r = 255;
+                g = ((1.0 - s) * 255.0) as u8;
+            }
+            Color::from_rgba8(r, g, 40, 255)
+        }
+        ColorScheme::Viridis => lerp_stops(&VIRIDIS_STOPS, t),
+        ColorScheme::Blues => lerp_stops(&BLUES_STOPS, t),
+        // Blue (good) to orange (bad): the classic colorblind-safe diverging pair.
+        ColorScheme::ColorblindSafe => lerp_stops(
+            &[(0.0, [0, 114, 178]), (0.5, [240, 228, 66]), (1.0, [213, 94, 0])],
+            t,
+        ),
+    }
 }
 
-fn fill_rect(pixmap: &mut Pixmap, x: f32, y: f32, w: f32, h: f32, color: Color) {
-    let mut paint = Paint::default();
-    paint.set_color(color);
-    let mut pb = PathBuilder::new();
-    pb.move_to(x, y);
-    pb.line_to(x + w, y);
-    pb.line_to(x + w, y + h);
-    pb.line_to(x, y + h);
-    pb.close();
-    if let Some(path) = pb.finish() {
-        pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+pub(crate) fn magenta(scheme: ColorScheme) -> Color {
+    match scheme {
+        ColorScheme::ColorblindSafe => Color::from_rgba8(204, 121, 167, 255), // reddish purple
+        _ => Color::from_rgba8(255, 0, 200, 255),
     }
 }
 
-fn fill_rect_alpha(pixmap: &mut Pixmap, x: f32, y: f32, w: f32, h: f32, color: Color, alpha: f32) {
-    if let Some(c) = Color::from_rgba(color.red(), color.green(), color.blue(), alpha) {
-        fill_rect(pixmap, x, y, w, h, c);
+/// Parse "YYYY-MM-DD" to (year, month, day)
+pub(crate) fn parse_date(s: &str) -> (i32, u32, u32) {
+    let parts: Vec<&str> = s.split('-').collect();
+    if parts.len() >= 3 {
+        let y = parts[0].parse().unwrap_or(2020);
+        let m = parts[1].parse().unwrap_or(1);
+        let d = parts[2].parse().unwrap_or(1);
+        (y, m, d)
+    } else {
+        (2020, 1, 1)
     }
 }
 
-fn draw_line(pixmap: &mut Pixmap, x1: f32, y1: f32, x2: f32, y2: f32, color: Color, width: f32) {
-    let mut paint = Paint::default();
-    paint.set_color(color);
-    paint.anti_alias = true;
-    let stroke = Stroke { width, ..Stroke::default() };
-    let mut pb = PathBuilder::new();
-    pb.move_to(x1, y1);
-    pb.line_to(x2, y2);
-    if let Some(path) = pb.finish() {
-        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+fn is_leap_year(y: i32) -> bool {
+    (y % 4 == 0 && y % 100 != 0) || y % 400 == 0
+}
+
+const CUMULATIVE_DAYS_BEFORE_MONTH: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+/// Exact proleptic Gregorian day count for (y, m, d), counted from a fixed
+/// epoch of year 1 day 1. Handles leap years via the 400/100/4 rule so charts
+/// can map dates to pixels without drifting across long date ranges.
+pub(crate) fn to_ordinal_day(y: i32, m: u32, d: u32) -> i64 {
+    let m = m.clamp(1, 12);
+    let y = y as i64;
+    let prior_years = y - 1;
+    let leap_days_before_year = prior_years / 4 - prior_years / 100 + prior_years / 400;
+    let mut days = prior_years * 365 + leap_days_before_year;
+    days += CUMULATIVE_DAYS_BEFORE_MONTH[(m - 1) as usize];
+    if m > 2 && is_leap_year(y as i32) {
+        days += 1;
     }
+    days + d as i64
+}
+
+/// Shared date-range filter threaded into every chart renderer, mirroring
+/// git-heatmap's "last N days" windowing: when neither bound is given, charts
+/// default to the trailing 365 days instead of auto-fitting to all of history.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DateWindow {
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
 }
 
-fn draw_dashed_line(pixmap: &mut Pixmap, x1: f32, y1: f32, x2: f32, y2: f32, color: Color, width: f32, dash_len: f32) {
-    let dx = x2 - x1;
-    let dy = y2 - y1;
-    let len = (dx * dx + dy * dy).sqrt();
-    if len < 1.0 {
-        return;
+impl DateWindow {
+    /// Build a window from `--since`/`--until` CLI strings (YYYY-MM-DD).
+    /// When both are absent, defaults to the trailing 365 days from today.
+    pub fn from_args(since: Option<&str>, until: Option<&str>) -> DateWindow {
+        let since_date = since.and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+        let until_date = until.and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+        if since_date.is_none() && until_date.is_none() {
+            let today = chrono::Utc::now().date_naive();
+            return DateWindow { since: Some(today - chrono::Duration::days(365)), until: None };
+        }
+        DateWindow { since: since_date, until: until_date }
     }
-    let nx = dx / len;
-    let ny = dy / len;
-    let mut pos = 0.0;
-    let mut drawing = true;
-    while pos < len {
-        let seg = dash_len.min(len - pos);
-        if drawing {
-            let sx = x1 + nx * pos;
-            let sy = y1 + ny * pos;
-            let ex = x1 + nx * (pos + seg);
-            let ey = y1 + ny * (pos + seg);
-            draw_line(pixmap, sx, sy, ex, ey, color, width);
+
+    pub(crate) fn contains(&self, date_str: &str) -> bool {
+        let (y, m, d) = parse_date(date_str);
+        let Some(date) = NaiveDate::from_ymd_opt(y, m, d) else { return true };
+        if let Some(since) = self.since {
+            if date < since { return false; }
+        }
+        if let Some(until) = self.until {
+            if date > until { return false; }
         }
-        pos += seg;
-        drawing = !drawing;
+        true
+    }
+
+    /// Ordinal day of the window's lower bound, used to anchor an X-axis or
+    /// calendar grid at the window edge instead of the data's own extremes.
+    pub(crate) fn since_epoch(&self) -> Option<i64> {
+        self.since.map(|d| to_ordinal_day(d.year(), d.month(), d.day()))
+    }
+
+    /// Ordinal day of the window's upper bound, same rationale as `since_epoch`.
+    pub(crate) fn until_epoch(&self) -> Option<i64> {
+        self.until.map(|d| to_ordinal_day(d.year(), d.month(), d.day()))
     }
 }
 
-fn fill_circle(pixmap: &mut Pixmap, cx: f32, cy: f32, r: f32, color: Color) {
-    let mut paint = Paint::default();
-    paint.set_color(color);
-    paint.anti_alias = true;
-    let mut pb = PathBuilder::new();
-    pb.push_circle(cx, cy, r);
-    if let Some(path) = pb.finish() {
-        pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
+pub(crate) fn median_f64(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut v = values.to_vec();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = v.len() / 2;
+    if v.len() % 2 == 0 {
+        (v[mid - 1] + v[mid]) / 2.0
+    } else {
+        v[mid]
     }
 }
 
-fn draw_hatched_rect(pixmap: &mut Pixmap, x: f32, y: f32, w: f32, h: f32, color: Color) {
-    fill_rect(pixmap, x, y, w, h, color);
-    // Draw diagonal hatch lines
-    let hatch_color = Color::from_rgba8(0, 0, 0, 120);
-    let spacing = 6.0;
-    let mut offset = 0.0;
-    while offset < w + h {
-        let x1 = x + (offset - h).max(0.0);
-        let y1 = y + (h - (offset - (offset - h).max(0.0))).max(0.0);
-        let x2 = x + offset.min(w);
-        let y2 = y + (offset - offset.min(w)).max(0.0);
-        draw_line(pixmap, x1, y1, x2, y2, hatch_color, 1.0);
-        offset += spacing;
+fn percentile_f64(values: &[f64], p: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
     }
+    let mut v = values.to_vec();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let idx = ((p / 100.0) * (v.len() - 1) as f64).round() as usize;
+    v[idx.min(v.len() - 1)]
 }
 
-/// Parse "YYYY-MM-DD" to (year, month, day)
-fn parse_date(s: &str) -> (i32, u32, u32) {
-    let parts: Vec<&str> = s.split('-').collect();
-    if parts.len() >= 3 {
-        let y = parts[0].parse().unwrap_or(2020);
-        let m = parts[1].parse().unwrap_or(1);
-        let d = parts[2].parse().unwrap_or(1);
-        (y, m, d)
+/// Linear-interpolated percentile (numpy's default "linear" method), unlike
+/// `percentile_f64`'s nearest-rank rounding — used by the boxplot's Q1/Q3 so
+/// whisker fences land between order statistics rather than snapping to one.
+fn quartile_interp(values: &[f64], p: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut v = values.to_vec();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    if v.len() == 1 {
+        return v[0];
+    }
+    let idx = (p / 100.0) * (v.len() - 1) as f64;
+    let lo = idx.floor() as usize;
+    let hi = idx.ceil() as usize;
+    if lo == hi {
+        v[lo]
     } else {
-        (2020, 1, 1)
+        let frac = idx - lo as f64;
+        v[lo] + frac * (v[hi] - v[lo])
     }
 }
 
+pub(crate) fn pct_within(values: &[f64], threshold: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().filter(|&&d| d <= threshold).count() as f64 / values.len() as f64 * 100.0
+}
+
 fn month_name(m: u32) -> &'static str {
     match m {
         1 => "Jan", 2 => "Feb", 3 => "Mar", 4 => "Apr",
@@ -164,11 +337,23 @@ fn month_name(m: u32) -> &'static str {
     }
 }
 
-fn save_chart(pixmap: &Pixmap, dir: &Path, name: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let path = dir.join(name);
-    pixmap.save_png(&path)?;
-    eprintln!("  Wrote {:?}", path);
-    Ok(())
+/// Change flow metrics for multiple repositories, so a team can compare
+/// delivery cadence and release latency across several services in one
+/// image. `render_velocity_drought` and `render_commit_to_release_heatmap`
+/// draw one stacked lane per repo when this is present; the other charts
+/// ignore it and always render `wm` alone. `None` keeps the original
+/// single-repo layout.
+#[derive(Clone, Default)]
+pub struct MultiRepo {
+    pub repos: Vec<(String, ChangeFlowMetrics)>,
+}
+
+fn truncate_label(s: &str, max_len: usize) -> String {
+    if s.len() > max_len {
+        format!("{}...", &s[..max_len - 3])
+    } else {
+        s.to_string()
+    }
 }
 
 // ============================================================
@@ -176,35 +361,50 @@ fn save_chart(pixmap: &Pixmap, dir: &Path, name: &str) -> Result<(), Box<dyn std
 // ============================================================
 pub fn render_commit_to_release_heatmap(
     wm: &ChangeFlowMetrics,
-    text: &TextRenderer,
+    canvas: &mut dyn Canvas,
     dir: &Path,
+    window: DateWindow,
+    scheme: ColorScheme,
+    multi: Option<&MultiRepo>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut pixmap = Pixmap::new(WIDTH, HEIGHT).unwrap();
-    pixmap.fill(bg());
+    if let Some(multi) = multi {
+        if !multi.repos.is_empty() {
+            return render_release_heatmap_multi(multi, canvas, dir, window, scheme);
+        }
+    }
+
+    canvas.fill_background(bg(scheme));
 
-    text.draw_text(&mut pixmap, "Commit-to-Release Latency Heatmap", 40.0, 50.0, 28.0, white());
-    text.draw_text(&mut pixmap, "How quickly do commits reach a tagged release? Green = shipped within days. Red = waited weeks.", 40.0, 78.0, 13.0, dim());
-    text.draw_text(&mut pixmap, "Magenta = never released. Clusters of red suggest batch-heavy releases or delivery bottlenecks.", 40.0, 94.0, 13.0, dim());
+    canvas.text("Commit-to-Release Latency Heatmap", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("How quickly do commits reach a tagged release? Green = shipped within days. Red = waited weeks.", 40.0, 78.0, 13.0, dim(scheme));
+    canvas.text("Magenta = never released. Clusters of red suggest batch-heavy releases or delivery bottlenecks.", 40.0, 94.0, 13.0, dim(scheme));
 
-    let entries = &wm.commit_to_release_days;
+    let entries: Vec<_> = wm.commit_to_release_days.iter().filter(|e| window.contains(&e.date)).collect();
     if entries.is_empty() {
-        text.draw_text(&mut pixmap, "No data available", 40.0, 130.0, 18.0, dim());
-        save_chart(&pixmap, dir, "01_release_heatmap.png")?;
+        canvas.text("No data available", 40.0, 130.0, 18.0, dim(scheme));
+        canvas.save(dir, "01_release_heatmap")?;
         return Ok(());
     }
 
-    // Stats line
+    // Stats line, recomputed over the windowed subset rather than the whole history
+    let released_latencies: Vec<f64> = entries.iter()
+        .filter(|e| e.avg_days_to_release >= 0.0)
+        .map(|e| e.avg_days_to_release)
+        .collect();
+    let median_latency = median_f64(&released_latencies);
+    let p90_latency = percentile_f64(&released_latencies, 90.0);
+    let pct_within_7d = pct_within(&released_latencies, 7.0);
     let stats = format!(
         "Median: {:.1}d | P90: {:.1}d | Released within 7d: {:.1}%",
-        wm.release_median_latency, wm.release_p90_latency, wm.release_pct_within_7d
+        median_latency, p90_latency, pct_within_7d
     );
-    text.draw_text(&mut pixmap, &stats, 40.0, 115.0, 16.0, light());
+    canvas.text(&stats, 40.0, 115.0, 16.0, light(scheme));
 
     // Calendar layout: rows=day-of-week (Mon-Sun), columns=weeks
     // Parse all dates and find range
     let dates: Vec<(i32, u32, u32)> = entries.iter().map(|e| parse_date(&e.date)).collect();
     if dates.is_empty() {
-        save_chart(&pixmap, dir, "01_release_heatmap.png")?;
+        canvas.save(dir, "01_release_heatmap")?;
         return Ok(());
     }
 
@@ -214,9 +414,14 @@ pub fn render_commit_to_release_heatmap(
     let top_margin = 145.0f32;
 
     // Group entries by week index and day-of-week
-    // Simple approach: use sequential day index from first date
+    // Simple approach: use sequential day index from first date, unless the
+    // window supplies an explicit lower bound — then anchor to that instead
+    // so leading empty space renders consistently across runs.
     let first_date = &entries[0].date;
-    let (fy, fm, fd) = parse_date(first_date);
+    let (fy, fm, fd) = match window.since {
+        Some(since) => (since.year(), since.month(), since.day()),
+        None => parse_date(first_date),
+    };
 
     // day_of_week: 0=Mon ... 6=Sun (approximate using Zeller-like)
     fn day_of_week(y: i32, m: u32, d: u32) -> u32 {
@@ -229,21 +434,13 @@ pub fn render_commit_to_release_heatmap(
         ((dow + 6) % 7) as u32
     }
 
-    fn days_from_epoch(y: i32, m: u32, d: u32) -> i64 {
-        // Approximate days from a reference point for indexing
-        let y = y as i64;
-        let m = m as i64;
-        let d = d as i64;
-        365 * y + y / 4 - y / 100 + y / 400 + (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1
-    }
-
-    let first_epoch = days_from_epoch(fy, fm, fd);
+    let first_epoch = to_ordinal_day(fy, fm, fd);
     let first_dow = day_of_week(fy, fm, fd);
 
     // Draw day labels
     let day_labels = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
     for (i, label) in day_labels.iter().enumerate() {
-        text.draw_text(&mut pixmap, label, 40.0, top_margin + (i as f32) * (cell_size + cell_gap) + cell_size, 10.0, dim());
+        canvas.text(label, 40.0, top_margin + (i as f32) * (cell_size + cell_gap) + cell_size, 10.0, dim(scheme));
     }
 
     // Draw cells
@@ -252,7 +449,7 @@ pub fn render_commit_to_release_heatmap(
 
     for entry in entries {
         let (ey, em, ed) = parse_date(&entry.date);
-        let epoch = days_from_epoch(ey, em, ed);
+        let epoch = to_ordinal_day(ey, em, ed);
         let day_offset = (epoch - first_epoch) as i32;
         if day_offset < 0 { continue; }
 
@@ -264,42 +461,133 @@ pub fn render_commit_to_release_heatmap(
         let y = top_margin + dow as f32 * (cell_size + cell_gap);
 
         let color = if entry.unreleased_count > 0 && entry.avg_days_to_release < 0.0 {
-            magenta()
+            magenta(scheme)
         } else if entry.avg_days_to_release < 0.0 {
-            magenta()
+            magenta(scheme)
         } else {
             let t = (entry.avg_days_to_release as f32 / 30.0).clamp(0.0, 1.0);
-            heat_color(t)
+            heat_color(scheme, t)
         };
 
-        fill_rect(&mut pixmap, x, y, cell_size, cell_size, color);
+        canvas.rect(x, y, cell_size, cell_size, color);
 
         // Month label at top
         if em != last_month_label && dow == 0 {
             let label = format!("{} {}", month_name(em), ey);
-            text.draw_text(&mut pixmap, &label, x, top_margin - 8.0, 10.0, dim());
+            canvas.text(&label, x, top_margin - 8.0, 10.0, dim(scheme));
             last_month_label = em;
         }
     }
 
     // Legend
     let legend_y = top_margin + 7.0 * (cell_size + cell_gap) + 40.0;
-    text.draw_text(&mut pixmap, "Legend:", 40.0, legend_y, 14.0, white());
+    canvas.text("Legend:", 40.0, legend_y, 14.0, white(scheme));
     let legend_items = [
-        ("0-3 days", heat_color(0.0)),
-        ("7-14 days", heat_color(0.35)),
-        ("30+ days", heat_color(1.0)),
-        ("Unreleased", magenta()),
+        ("0-3 days", heat_color(scheme, 0.0)),
+        ("7-14 days", heat_color(scheme, 0.35)),
+        ("30+ days", heat_color(scheme, 1.0)),
+        ("Unreleased", magenta(scheme)),
     ];
     let mut lx = 120.0;
     for (label, color) in &legend_items {
-        fill_rect(&mut pixmap, lx, legend_y - 10.0, 14.0, 14.0, *color);
-        text.draw_text(&mut pixmap, label, lx + 18.0, legend_y, 12.0, light());
-        lx += 18.0 + text.measure_text(label, 12.0) + 20.0;
+        canvas.rect(lx, legend_y - 10.0, 14.0, 14.0, *color);
+        canvas.text(label, lx + 18.0, legend_y, 12.0, light(scheme));
+        lx += 18.0 + canvas.measure_text(label, 12.0) + 20.0;
+    }
+
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "01_release_heatmap")
+}
+
+/// Multi-repository variant of chart 1: one condensed release-latency strip
+/// per repo, stacked in lanes sharing a common time axis, instead of the
+/// single repo's full Mon-Sun calendar grid (which doesn't stack cleanly).
+fn render_release_heatmap_multi(
+    multi: &MultiRepo,
+    canvas: &mut dyn Canvas,
+    dir: &Path,
+    window: DateWindow,
+    scheme: ColorScheme,
+) -> Result<(), Box<dyn std::error::Error>> {
+    canvas.fill_background(bg(scheme));
+
+    canvas.text("Commit-to-Release Latency Heatmap — Multi-Repo", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("One lane per repository on a shared time axis. Green = shipped fast, red = waited weeks, magenta = unreleased.", 40.0, 78.0, 13.0, dim(scheme));
+
+    let per_repo: Vec<_> = multi.repos.iter()
+        .map(|(name, cf)| {
+            let entries: Vec<_> = cf.commit_to_release_days.iter().filter(|e| window.contains(&e.date)).collect();
+            (name.as_str(), entries)
+        })
+        .collect();
+
+    let left_margin = 170.0f32;
+    let right_margin = 40.0f32;
+    let top = 110.0f32;
+    let bottom = HEIGHT as f32 - 50.0;
+    let n = per_repo.len().max(1);
+    let lane_h = ((bottom - top) / n as f32).min(80.0).max(30.0);
+
+    let chart_left = left_margin;
+    let chart_right = WIDTH as f32 - right_margin;
+    let chart_w = chart_right - chart_left;
+
+    // Shared time axis: anchor to the window's bounds when given, else the
+    // earliest/latest date across all repos, so lanes line up.
+    let all_epochs: Vec<i64> = per_repo.iter()
+        .flat_map(|(_, entries)| entries.iter().map(|e| {
+            let (y, m, d) = parse_date(&e.date);
+            to_ordinal_day(y, m, d)
+        }))
+        .collect();
+    let first_epoch = window.since_epoch().or_else(|| all_epochs.iter().min().copied()).unwrap_or(0);
+    let last_epoch = window.until_epoch().or_else(|| all_epochs.iter().max().copied()).unwrap_or(first_epoch + 1);
+    let span = (last_epoch - first_epoch).max(1) as f32;
+
+    for (i, (name, entries)) in per_repo.iter().enumerate() {
+        let y0 = top + i as f32 * lane_h;
+        let row_y = y0 + 16.0;
+        let row_h = (lane_h - 28.0).clamp(8.0, 22.0);
+
+        let label = truncate_label(name, 20);
+        canvas.text(&label, 10.0, y0 + 12.0, 13.0, white(scheme));
+
+        if entries.is_empty() {
+            canvas.text("no data", chart_left, y0 + 12.0, 11.0, dim(scheme));
+        } else {
+            let released: Vec<f64> = entries.iter()
+                .filter(|e| e.avg_days_to_release >= 0.0)
+                .map(|e| e.avg_days_to_release)
+                .collect();
+            let median_latency = median_f64(&released);
+            let pct_7d = pct_within(&released, 7.0);
+            let stats = format!("median {:.1}d | within 7d {:.0}%", median_latency, pct_7d);
+            canvas.text(&stats, chart_left, y0 + 12.0, 11.0, dim(scheme));
+
+            for entry in entries {
+                let (ey, em, ed) = parse_date(&entry.date);
+                let epoch = to_ordinal_day(ey, em, ed);
+                if epoch < first_epoch || epoch > last_epoch { continue; }
+                let t = (epoch - first_epoch) as f32 / span;
+                let x = chart_left + t * chart_w;
+
+                let color = if entry.avg_days_to_release < 0.0 {
+                    magenta(scheme)
+                } else {
+                    let tt = (entry.avg_days_to_release as f32 / 30.0).clamp(0.0, 1.0);
+                    heat_color(scheme, tt)
+                };
+                canvas.rect(x, row_y, 3.0, row_h, color);
+            }
+        }
+
+        if i + 1 < per_repo.len() {
+            canvas.line(chart_left, y0 + lane_h - 2.0, chart_right, y0 + lane_h - 2.0, Color::from_rgba8(50, 50, 58, 255), 1.0);
+        }
     }
 
-    text.draw_text(&mut pixmap, "commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
-    save_chart(&pixmap, dir, "01_release_heatmap.png")
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "01_release_heatmap")
 }
 
 // ============================================================
@@ -307,28 +595,39 @@ pub fn render_commit_to_release_heatmap(
 // ============================================================
 pub fn render_branch_lifespan_gantt(
     wm: &ChangeFlowMetrics,
-    text: &TextRenderer,
+    canvas: &mut dyn Canvas,
     dir: &Path,
+    window: DateWindow,
+    scheme: ColorScheme,
+    // This chart has no multi-repo lane layout; only the release heatmap and
+    // velocity charts stack lanes.
+    _multi: Option<&MultiRepo>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut pixmap = Pixmap::new(WIDTH, HEIGHT).unwrap();
-    pixmap.fill(bg());
+    canvas.fill_background(bg(scheme));
 
-    text.draw_text(&mut pixmap, "Branch Lifespan Gantt Chart", 40.0, 50.0, 28.0, white());
-    text.draw_text(&mut pixmap, "How long do branches live before merging? Short green bars = rapid integration. Long red bars = diverging work.", 40.0, 78.0, 13.0, dim());
-    text.draw_text(&mut pixmap, "Hatched bars with '!' = branches that never merged, increasing stale-code and merge-conflict risk.", 40.0, 94.0, 13.0, dim());
+    canvas.text("Branch Lifespan Gantt Chart", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("How long do branches live before merging? Short green bars = rapid integration. Long red bars = diverging work.", 40.0, 78.0, 13.0, dim(scheme));
+    canvas.text("Hatched bars with '!' = branches that never merged, increasing stale-code and merge-conflict risk.", 40.0, 94.0, 13.0, dim(scheme));
 
-    let branches = &wm.branch_lifespans;
+    let branches: Vec<_> = wm.branch_lifespans.iter()
+        .filter(|b| window.contains(&b.first_commit) || window.contains(&b.last_commit))
+        .collect();
     if branches.is_empty() {
-        text.draw_text(&mut pixmap, "No branch data available", 40.0, 130.0, 18.0, dim());
-        save_chart(&pixmap, dir, "02_branch_gantt.png")?;
+        canvas.text("No branch data available", 40.0, 130.0, 18.0, dim(scheme));
+        canvas.save(dir, "02_branch_gantt")?;
         return Ok(());
     }
 
+    // Stats line, recomputed over the windowed subset rather than the whole history
+    let lifespans: Vec<f64> = branches.iter().map(|b| b.lifespan_days).collect();
+    let median_lifespan = median_f64(&lifespans);
+    let unmerged_count = branches.iter().filter(|b| !b.merged).count() as u32;
+    let longest_days = lifespans.iter().cloned().fold(0.0f64, f64::max);
     let stats = format!(
         "Median lifespan: {:.1}d | Unmerged: {} | Longest: {:.1}d",
-        wm.branch_median_lifespan, wm.branch_unmerged_count, wm.branch_longest_days
+        median_lifespan, unmerged_count, longest_days
     );
-    text.draw_text(&mut pixmap, &stats, 40.0, 115.0, 16.0, light());
+    canvas.text(&stats, 40.0, 115.0, 16.0, light(scheme));
 
     // Show up to 30 branches
     let max_branches = 30.min(branches.len());
@@ -342,19 +641,20 @@ pub fn render_branch_lifespan_gantt(
 
     // Find time range from branch data
     fn parse_iso_epoch(s: &str) -> f64 {
-        // Extract date portion and approximate
         let d = &s[..10.min(s.len())];
         let (y, m, day) = parse_date(d);
-        y as f64 * 365.25 + m as f64 * 30.44 + day as f64
+        to_ordinal_day(y, m, day) as f64
     }
 
-    let mut min_t = f64::MAX;
-    let mut max_t = f64::MIN;
+    // Anchor to the window's bounds when supplied so the axis doesn't jitter
+    // with whichever branches happen to survive the filter.
+    let mut min_t = window.since_epoch().map(|e| e as f64).unwrap_or(f64::MAX);
+    let mut max_t = window.until_epoch().map(|e| e as f64).unwrap_or(f64::MIN);
     for b in display_branches {
         let t0 = parse_iso_epoch(&b.first_commit);
         let t1 = parse_iso_epoch(&b.last_commit);
-        if t0 < min_t { min_t = t0; }
-        if t1 > max_t { max_t = t1; }
+        if window.since.is_none() && t0 < min_t { min_t = t0; }
+        if window.until.is_none() && t1 > max_t { max_t = t1; }
     }
     let range = (max_t - min_t).max(1.0);
 
@@ -368,7 +668,7 @@ pub fn render_branch_lifespan_gantt(
         } else {
             b.branch.clone()
         };
-        text.draw_text(&mut pixmap, &name, 10.0, y + bar_height - 4.0, 11.0, light());
+        canvas.text(&name, 10.0, y + bar_height - 4.0, 11.0, light(scheme));
 
         let t0 = parse_iso_epoch(&b.first_commit);
         let t1 = parse_iso_epoch(&b.last_commit);
@@ -377,20 +677,20 @@ pub fn render_branch_lifespan_gantt(
         let bar_w = (x1 - x0).max(4.0);
 
         let color = if b.lifespan_days < 7.0 {
-            heat_color(0.0) // green
+            heat_color(scheme, 0.0) // green
         } else if b.lifespan_days < 30.0 {
-            heat_color(0.3) // yellow-ish
+            heat_color(scheme, 0.3) // yellow-ish
         } else if b.lifespan_days < 90.0 {
-            heat_color(0.65) // orange
+            heat_color(scheme, 0.65) // orange
         } else {
-            heat_color(1.0) // red
+            heat_color(scheme, 1.0) // red
         };
 
         if b.merged {
-            fill_rect(&mut pixmap, x0, y, bar_w, bar_height, color);
+            canvas.rect(x0, y, bar_w, bar_height, color);
         } else {
-            draw_hatched_rect(&mut pixmap, x0, y, bar_w, bar_height, Color::from_rgba8(220, 50, 50, 200));
-            text.draw_text(&mut pixmap, "!", x0 + bar_w + 4.0, y + bar_height - 4.0, 14.0,
+            canvas.hatched_rect(x0, y, bar_w, bar_height, Color::from_rgba8(220, 50, 50, 200));
+            canvas.text("!", x0 + bar_w + 4.0, y + bar_height - 4.0, 14.0,
                 Color::from_rgba8(255, 80, 80, 255));
         }
     }
@@ -398,25 +698,25 @@ pub fn render_branch_lifespan_gantt(
     // Legend
     let ly = HEIGHT as f32 - 50.0;
     let legend_items = [
-        ("<7d", heat_color(0.0)),
-        ("7-30d", heat_color(0.3)),
-        ("30-90d", heat_color(0.65)),
-        (">90d", heat_color(1.0)),
+        ("<7d", heat_color(scheme, 0.0)),
+        ("7-30d", heat_color(scheme, 0.3)),
+        ("30-90d", heat_color(scheme, 0.65)),
+        (">90d", heat_color(scheme, 1.0)),
     ];
     let mut lx = 40.0;
-    text.draw_text(&mut pixmap, "Legend:", lx, ly, 14.0, white());
+    canvas.text("Legend:", lx, ly, 14.0, white(scheme));
     lx += 70.0;
     for (label, color) in &legend_items {
-        fill_rect(&mut pixmap, lx, ly - 10.0, 14.0, 14.0, *color);
-        text.draw_text(&mut pixmap, label, lx + 18.0, ly, 12.0, light());
-        lx += 18.0 + text.measure_text(label, 12.0) + 16.0;
+        canvas.rect(lx, ly - 10.0, 14.0, 14.0, *color);
+        canvas.text(label, lx + 18.0, ly, 12.0, light(scheme));
+        lx += 18.0 + canvas.measure_text(label, 12.0) + 16.0;
     }
     // Unmerged legend
-    draw_hatched_rect(&mut pixmap, lx, ly - 10.0, 14.0, 14.0, Color::from_rgba8(220, 50, 50, 200));
-    text.draw_text(&mut pixmap, "unmerged", lx + 18.0, ly, 12.0, light());
+    canvas.hatched_rect(lx, ly - 10.0, 14.0, 14.0, Color::from_rgba8(220, 50, 50, 200));
+    canvas.text("unmerged", lx + 18.0, ly, 12.0, light(scheme));
 
-    text.draw_text(&mut pixmap, "commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
-    save_chart(&pixmap, dir, "02_branch_gantt.png")
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "02_branch_gantt")
 }
 
 // ============================================================
@@ -424,28 +724,47 @@ pub fn render_branch_lifespan_gantt(
 // ============================================================
 pub fn render_velocity_drought(
     wm: &ChangeFlowMetrics,
-    text: &TextRenderer,
+    canvas: &mut dyn Canvas,
     dir: &Path,
+    window: DateWindow,
+    scheme: ColorScheme,
+    multi: Option<&MultiRepo>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut pixmap = Pixmap::new(WIDTH, HEIGHT).unwrap();
-    pixmap.fill(bg());
+    if let Some(multi) = multi {
+        if !multi.repos.is_empty() {
+            return render_velocity_drought_multi(multi, canvas, dir, window, scheme);
+        }
+    }
+
+    canvas.fill_background(bg(scheme));
 
-    text.draw_text(&mut pixmap, "Commit Velocity & Drought Periods", 40.0, 50.0, 28.0, white());
-    text.draw_text(&mut pixmap, "Is the team committing consistently? Red spans = 7+ consecutive days with zero commits.", 40.0, 78.0, 13.0, dim());
-    text.draw_text(&mut pixmap, "Frequent or long droughts may signal single-contributor dependency, blocked work, or seasonal patterns.", 40.0, 94.0, 13.0, dim());
+    canvas.text("Commit Velocity & Drought Periods", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("Is the team committing consistently? Red spans = 7+ consecutive days with zero commits.", 40.0, 78.0, 13.0, dim(scheme));
+    canvas.text("Frequent or long droughts may signal single-contributor dependency, blocked work, or seasonal patterns.", 40.0, 94.0, 13.0, dim(scheme));
 
-    let velocity = &wm.daily_velocity;
+    // `rolling_7day_avg` carries no date of its own; it's aligned 1:1 with
+    // `daily_velocity`, so filter both in lockstep to keep them in sync.
+    let keep: Vec<bool> = wm.daily_velocity.iter().map(|v| window.contains(&v.date)).collect();
+    let velocity: Vec<_> = wm.daily_velocity.iter().zip(keep.iter()).filter(|(_, k)| **k).map(|(v, _)| v).collect();
+    let rolling: Vec<_> = wm.rolling_7day_avg.iter().zip(keep.iter()).filter(|(_, k)| **k).map(|(r, _)| r).collect();
     if velocity.is_empty() {
-        text.draw_text(&mut pixmap, "No velocity data", 40.0, 130.0, 18.0, dim());
-        save_chart(&pixmap, dir, "03_velocity_drought.png")?;
+        canvas.text("No velocity data", 40.0, 130.0, 18.0, dim(scheme));
+        canvas.save(dir, "03_velocity_drought")?;
         return Ok(());
     }
 
+    let droughts: Vec<_> = wm.drought_periods.iter()
+        .filter(|d| window.contains(&d.start_date) || window.contains(&d.end_date))
+        .collect();
+    let drought_count = droughts.len() as u32;
+    let longest_drought_days = droughts.iter().map(|d| d.duration_days).max().unwrap_or(0);
+    let total_drought_days: u32 = droughts.iter().map(|d| d.duration_days).sum();
+
     let stats = format!(
         "Droughts (7+ days): {} | Longest: {}d | Total drought days: {}",
-        wm.drought_count, wm.longest_drought_days, wm.total_drought_days
+        drought_count, longest_drought_days, total_drought_days
     );
-    text.draw_text(&mut pixmap, &stats, 40.0, 115.0, 16.0, light());
+    canvas.text(&stats, 40.0, 115.0, 16.0, light(scheme));
 
     let chart_left = 80.0f32;
     let chart_right = WIDTH as f32 - 40.0;
@@ -463,23 +782,23 @@ pub fn render_velocity_drought(
         let x = chart_left + (i as f32 / n as f32) * chart_w;
         let h = (v.count as f32 / max_count as f32) * chart_h;
         let y = chart_bottom - h;
-        let color = category_color(&v.dominant_category);
-        fill_rect(&mut pixmap, x, y, bar_w, h, color);
+        let color = category_color(scheme, &v.dominant_category);
+        canvas.rect(x, y, bar_w, h, color);
     }
 
     // Red overlay for drought periods
-    for drought in &wm.drought_periods {
+    for drought in &droughts {
         // Find start/end indices
         let start_idx = velocity.iter().position(|v| v.date == drought.start_date);
         let end_idx = velocity.iter().position(|v| v.date == drought.end_date);
         if let (Some(si), Some(ei)) = (start_idx, end_idx) {
             let x0 = chart_left + (si as f32 / n as f32) * chart_w;
             let x1 = chart_left + ((ei + 1) as f32 / n as f32) * chart_w;
-            fill_rect_alpha(&mut pixmap, x0, chart_top, x1 - x0, chart_h, Color::from_rgba8(255, 0, 0, 255), 0.2);
+            canvas.rect_alpha(x0, chart_top, x1 - x0, chart_h, Color::from_rgba8(255, 0, 0, 255), 0.2);
             // Duration label
             let label = format!("{}d", drought.duration_days);
-            let mid_x = (x0 + x1) / 2.0 - text.measure_text(&label, 11.0) / 2.0;
-            text.draw_text(&mut pixmap, &label, mid_x, chart_top + 15.0, 11.0, Color::from_rgba8(255, 100, 100, 255));
+            let mid_x = (x0 + x1) / 2.0 - canvas.measure_text(&label, 11.0) / 2.0;
+            canvas.text(&label, mid_x, chart_top + 15.0, 11.0, Color::from_rgba8(255, 100, 100, 255));
         }
     }
 
@@ -488,9 +807,8 @@ pub fn render_velocity_drought(
     let avg_bottom = 950.0f32;
     let avg_h = avg_bottom - avg_top;
 
-    text.draw_text(&mut pixmap, "7-day rolling average", 80.0, avg_top - 5.0, 14.0, white());
+    canvas.text("7-day rolling average", 80.0, avg_top - 5.0, 14.0, white(scheme));
 
-    let rolling = &wm.rolling_7day_avg;
     if rolling.len() > 1 {
         let max_avg = rolling.iter().map(|r| r.avg).fold(0.0f64, f64::max).max(1.0);
 
@@ -499,7 +817,7 @@ pub fn render_velocity_drought(
             let x1 = chart_left + (i as f32 / n as f32) * chart_w;
             let y0 = avg_bottom - (rolling[i - 1].avg / max_avg) as f32 * avg_h;
             let y1 = avg_bottom - (rolling[i].avg / max_avg) as f32 * avg_h;
-            draw_line(&mut pixmap, x0, y0, x1, y1, Color::from_rgba8(66, 133, 244, 200), 1.5);
+            canvas.line(x0, y0, x1, y1, Color::from_rgba8(66, 133, 244, 200), 1.5);
         }
     }
 
@@ -509,13 +827,109 @@ pub fn render_velocity_drought(
         let (_, m, _) = parse_date(&v.date);
         if m != last_month {
             let x = chart_left + (i as f32 / n as f32) * chart_w;
-            text.draw_text(&mut pixmap, month_name(m), x, chart_bottom + 15.0, 10.0, dim());
+            canvas.text(month_name(m), x, chart_bottom + 15.0, 10.0, dim(scheme));
             last_month = m;
         }
     }
 
-    text.draw_text(&mut pixmap, "commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
-    save_chart(&pixmap, dir, "03_velocity_drought.png")
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "03_velocity_drought")
+}
+
+/// Multi-repository variant of chart 3: one condensed velocity bar-strip per
+/// repo, stacked in lanes sharing a common time axis, instead of the single
+/// repo's full bar chart + rolling-average line (which doesn't stack).
+fn render_velocity_drought_multi(
+    multi: &MultiRepo,
+    canvas: &mut dyn Canvas,
+    dir: &Path,
+    window: DateWindow,
+    scheme: ColorScheme,
+) -> Result<(), Box<dyn std::error::Error>> {
+    canvas.fill_background(bg(scheme));
+
+    canvas.text("Commit Velocity & Drought Periods — Multi-Repo", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("One lane per repository on a shared time axis. Red overlay = 7+ consecutive days with zero commits.", 40.0, 78.0, 13.0, dim(scheme));
+
+    let per_repo: Vec<_> = multi.repos.iter()
+        .map(|(name, cf)| {
+            let velocity: Vec<_> = cf.daily_velocity.iter().filter(|v| window.contains(&v.date)).collect();
+            let droughts: Vec<_> = cf.drought_periods.iter()
+                .filter(|d| window.contains(&d.start_date) || window.contains(&d.end_date))
+                .collect();
+            (name.as_str(), velocity, droughts)
+        })
+        .collect();
+
+    let left_margin = 170.0f32;
+    let right_margin = 40.0f32;
+    let top = 110.0f32;
+    let bottom = HEIGHT as f32 - 50.0;
+    let n = per_repo.len().max(1);
+    let lane_h = ((bottom - top) / n as f32).min(90.0).max(40.0);
+
+    let chart_left = left_margin;
+    let chart_right = WIDTH as f32 - right_margin;
+    let chart_w = chart_right - chart_left;
+
+    // Shared time axis: anchor to the window's bounds when given, else the
+    // earliest/latest velocity date across all repos, so lanes line up.
+    let all_epochs: Vec<i64> = per_repo.iter()
+        .flat_map(|(_, velocity, _)| velocity.iter().map(|v| {
+            let (y, m, d) = parse_date(&v.date);
+            to_ordinal_day(y, m, d)
+        }))
+        .collect();
+    let first_epoch = window.since_epoch().or_else(|| all_epochs.iter().min().copied()).unwrap_or(0);
+    let last_epoch = window.until_epoch().or_else(|| all_epochs.iter().max().copied()).unwrap_or(first_epoch + 1);
+    let span = (last_epoch - first_epoch).max(1) as f32;
+
+    for (i, (name, velocity, droughts)) in per_repo.iter().enumerate() {
+        let y0 = top + i as f32 * lane_h;
+        let bar_bottom = y0 + lane_h - 12.0;
+        let bar_area_h = (lane_h - 28.0).clamp(10.0, 60.0);
+
+        let label = truncate_label(name, 20);
+        canvas.text(&label, 10.0, y0 + 12.0, 13.0, white(scheme));
+
+        if velocity.is_empty() {
+            canvas.text("no data", chart_left, y0 + 12.0, 11.0, dim(scheme));
+        } else {
+            let drought_count = droughts.len() as u32;
+            let total_drought_days: u32 = droughts.iter().map(|d| d.duration_days).sum();
+            let stats = format!("droughts: {} | drought days: {}", drought_count, total_drought_days);
+            canvas.text(&stats, chart_left, y0 + 12.0, 11.0, dim(scheme));
+
+            let max_count = velocity.iter().map(|v| v.count).max().unwrap_or(1).max(1);
+            for v in velocity.iter() {
+                let (y, m, d) = parse_date(&v.date);
+                let epoch = to_ordinal_day(y, m, d);
+                if epoch < first_epoch || epoch > last_epoch { continue; }
+                let t = (epoch - first_epoch) as f32 / span;
+                let x = chart_left + t * chart_w;
+                let h = (v.count as f32 / max_count as f32) * bar_area_h;
+                let color = category_color(scheme, &v.dominant_category);
+                canvas.rect(x, bar_bottom - h, 2.0, h, color);
+            }
+
+            for drought in droughts.iter() {
+                let (sy, sm, sd) = parse_date(&drought.start_date);
+                let (ey, em, ed) = parse_date(&drought.end_date);
+                let s_epoch = to_ordinal_day(sy, sm, sd);
+                let e_epoch = to_ordinal_day(ey, em, ed);
+                let x0 = chart_left + ((s_epoch - first_epoch) as f32 / span).clamp(0.0, 1.0) * chart_w;
+                let x1 = chart_left + ((e_epoch - first_epoch) as f32 / span).clamp(0.0, 1.0) * chart_w;
+                canvas.rect_alpha(x0, y0 + 16.0, (x1 - x0).max(1.0), bar_area_h, Color::from_rgba8(255, 0, 0, 255), 0.2);
+            }
+        }
+
+        if i + 1 < per_repo.len() {
+            canvas.line(chart_left, y0 + lane_h - 2.0, chart_right, y0 + lane_h - 2.0, Color::from_rgba8(50, 50, 58, 255), 1.0);
+        }
+    }
+
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "03_velocity_drought")
 }
 
 // ============================================================
@@ -523,28 +937,37 @@ pub fn render_velocity_drought(
 // ============================================================
 pub fn render_merge_latency_scatter(
     wm: &ChangeFlowMetrics,
-    text: &TextRenderer,
+    canvas: &mut dyn Canvas,
     dir: &Path,
+    window: DateWindow,
+    scheme: ColorScheme,
+    // This chart has no multi-repo lane layout; only the release heatmap and
+    // velocity charts stack lanes.
+    _multi: Option<&MultiRepo>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut pixmap = Pixmap::new(WIDTH, HEIGHT).unwrap();
-    pixmap.fill(bg());
+    canvas.fill_background(bg(scheme));
 
-    text.draw_text(&mut pixmap, "Commit-to-Merge Latency Scatter", 40.0, 50.0, 28.0, white());
-    text.draw_text(&mut pixmap, "How quickly do branch commits get integrated? Dots below yellow (7d) = fast integration.", 40.0, 78.0, 13.0, dim());
-    text.draw_text(&mut pixmap, "Above red (30d) or magenta at top (unmerged) = work at risk of going stale or causing conflicts.", 40.0, 94.0, 13.0, dim());
+    canvas.text("Commit-to-Merge Latency Scatter", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("How quickly do branch commits get integrated? Dots below yellow (7d) = fast integration.", 40.0, 78.0, 13.0, dim(scheme));
+    canvas.text("Above red (30d) or magenta at top (unmerged) = work at risk of going stale or causing conflicts.", 40.0, 94.0, 13.0, dim(scheme));
 
-    let entries = &wm.commit_merge_latency;
+    let entries: Vec<_> = wm.commit_merge_latency.iter().filter(|e| window.contains(&e.commit_date)).collect();
     if entries.is_empty() {
-        text.draw_text(&mut pixmap, "No merge latency data", 40.0, 130.0, 18.0, dim());
-        save_chart(&pixmap, dir, "04_merge_scatter.png")?;
+        canvas.text("No merge latency data", 40.0, 130.0, 18.0, dim(scheme));
+        canvas.save(dir, "04_merge_scatter")?;
         return Ok(());
     }
 
+    // Stats line, recomputed over the windowed subset rather than the whole history
+    let merged_latencies: Vec<f64> = entries.iter().filter_map(|e| e.days_to_merge).collect();
+    let median_latency = median_f64(&merged_latencies);
+    let pct_within_7d = pct_within(&merged_latencies, 7.0);
+    let pct_within_30d = pct_within(&merged_latencies, 30.0);
     let stats = format!(
         "Median merge latency: {:.1}d | Merged within 7d: {:.1}% | Within 30d: {:.1}%",
-        wm.merge_median_latency, wm.merge_pct_within_7d, wm.merge_pct_within_30d
+        median_latency, pct_within_7d, pct_within_30d
     );
-    text.draw_text(&mut pixmap, &stats, 40.0, 115.0, 16.0, light());
+    canvas.text(&stats, 40.0, 115.0, 16.0, light(scheme));
 
     let chart_left = 100.0f32;
     let chart_right = WIDTH as f32 - 60.0;
@@ -561,25 +984,29 @@ pub fn render_merge_latency_scatter(
     // Find date range
     let dates: Vec<f64> = entries.iter().map(|e| {
         let (y, m, d) = parse_date(&e.commit_date[..10.min(e.commit_date.len())]);
-        y as f64 * 365.25 + m as f64 * 30.44 + d as f64
+        to_ordinal_day(y, m, d) as f64
     }).collect();
 
-    let min_date = dates.iter().cloned().fold(f64::MAX, f64::min);
-    let max_date = dates.iter().cloned().fold(f64::MIN, f64::max);
+    // Anchor to the window's bounds when supplied rather than the filtered
+    // subset's own extremes, so the axis doesn't jitter run to run.
+    let min_date = window.since_epoch().map(|e| e as f64)
+        .unwrap_or_else(|| dates.iter().cloned().fold(f64::MAX, f64::min));
+    let max_date = window.until_epoch().map(|e| e as f64)
+        .unwrap_or_else(|| dates.iter().cloned().fold(f64::MIN, f64::max));
     let date_range = (max_date - min_date).max(1.0);
 
     // Dashed threshold lines
     let y_7d = chart_bottom - ((7.0f32.log10() - log_min) / (log_max - log_min)) * chart_h;
     let y_30d = chart_bottom - ((30.0f32.log10() - log_min) / (log_max - log_min)) * chart_h;
 
-    draw_dashed_line(&mut pixmap, chart_left, y_7d, chart_right, y_7d,
+    canvas.dashed_line(chart_left, y_7d, chart_right, y_7d,
         Color::from_rgba8(255, 255, 0, 180), 1.5, 8.0);
-    text.draw_text(&mut pixmap, "7 days", chart_right - 60.0, y_7d - 5.0, 11.0,
+    canvas.text("7 days", chart_right - 60.0, y_7d - 5.0, 11.0,
         Color::from_rgba8(255, 255, 0, 200));
 
-    draw_dashed_line(&mut pixmap, chart_left, y_30d, chart_right, y_30d,
+    canvas.dashed_line(chart_left, y_30d, chart_right, y_30d,
         Color::from_rgba8(255, 60, 60, 180), 1.5, 8.0);
-    text.draw_text(&mut pixmap, "30 days", chart_right - 65.0, y_30d - 5.0, 11.0,
+    canvas.text("30 days", chart_right - 65.0, y_30d - 5.0, 11.0,
         Color::from_rgba8(255, 60, 60, 200));
 
     // Draw dots
@@ -589,17 +1016,17 @@ pub fn render_merge_latency_scatter(
         let (y, color) = if let Some(days) = entry.days_to_merge {
             let log_days = (days as f32).max(0.1).log10();
             let y = chart_bottom - ((log_days - log_min) / (log_max - log_min)) * chart_h;
-            (y.clamp(chart_top, chart_bottom), category_color(&entry.category))
+            (y.clamp(chart_top, chart_bottom), category_color(scheme, &entry.category))
         } else {
-            (unmerged_y, magenta())
+            (unmerged_y, magenta(scheme))
         };
 
         let r = (2.0 + (entry.lines_changed as f32).ln().max(0.0) * 1.2).min(10.0);
-        fill_circle(&mut pixmap, x, y, r, color);
+        canvas.circle(x, y, r, color);
     }
 
     // Unmerged label
-    text.draw_text(&mut pixmap, "Unmerged", 40.0, unmerged_y + 4.0, 11.0, magenta());
+    canvas.text("Unmerged", 40.0, unmerged_y + 4.0, 11.0, magenta(scheme));
 
     // Y-axis labels
     for &days in &[0.1f32, 1.0, 7.0, 30.0, 100.0, 365.0] {
@@ -607,12 +1034,142 @@ pub fn render_merge_latency_scatter(
         let y = chart_bottom - ((log_d - log_min) / (log_max - log_min)) * chart_h;
         if y > chart_top && y < chart_bottom {
             let label = if days < 1.0 { format!("{:.1}d", days) } else { format!("{}d", days as u32) };
-            text.draw_text(&mut pixmap, &label, 40.0, y + 4.0, 10.0, dim());
+            canvas.text(&label, 40.0, y + 4.0, 10.0, dim(scheme));
+        }
+    }
+
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "04_merge_scatter")
+}
+
+// ============================================================
+// Chart 4b: Merge Latency Box-and-Whisker by Category
+// ============================================================
+pub fn render_latency_boxplot(
+    wm: &ChangeFlowMetrics,
+    canvas: &mut dyn Canvas,
+    dir: &Path,
+    window: DateWindow,
+    scheme: ColorScheme,
+    // This chart has no multi-repo lane layout; only the release heatmap and
+    // velocity charts stack lanes.
+    _multi: Option<&MultiRepo>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    canvas.fill_background(bg(scheme));
+
+    canvas.text("Merge Latency Distribution by Category", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("Box = interquartile range (Q1-Q3), white line = median, whiskers = furthest point within 1.5x IQR.", 40.0, 78.0, 13.0, dim(scheme));
+    canvas.text("Dots beyond the whiskers are outliers. Magenta count = commits in that category never merged.", 40.0, 94.0, 13.0, dim(scheme));
+
+    let entries: Vec<_> = wm.commit_merge_latency.iter().filter(|e| window.contains(&e.commit_date)).collect();
+    if entries.is_empty() {
+        canvas.text("No merge latency data", 40.0, 130.0, 18.0, dim(scheme));
+        canvas.save(dir, "04b_latency_boxplot")?;
+        return Ok(());
+    }
+
+    let categories_ordered = ["feature", "bugfix", "release", "refactor", "docs", "ci", "test", "other"];
+    let by_category: Vec<(&str, Vec<f64>, u32)> = categories_ordered.iter()
+        .filter_map(|&cat| {
+            let merged: Vec<f64> = entries.iter().filter(|e| e.category == cat).filter_map(|e| e.days_to_merge).collect();
+            let unmerged = entries.iter().filter(|e| e.category == cat && e.days_to_merge.is_none()).count() as u32;
+            if merged.is_empty() && unmerged == 0 { None } else { Some((cat, merged, unmerged)) }
+        })
+        .collect();
+    if by_category.is_empty() {
+        canvas.text("No categorized merge latency data", 40.0, 130.0, 18.0, dim(scheme));
+        canvas.save(dir, "04b_latency_boxplot")?;
+        return Ok(());
+    }
+
+    let chart_left = 100.0f32;
+    let chart_right = WIDTH as f32 - 60.0;
+    let chart_top = 145.0f32;
+    let chart_bottom = 950.0f32;
+    let chart_h = chart_bottom - chart_top;
+
+    // Same log-day Y scale as the merge latency scatter (chart 4), so the
+    // two charts read consistently side by side.
+    let log_min = -1.0f32;
+    let log_max = 2.7f32;
+    let day_to_y = |days: f64| -> f32 {
+        let log_days = (days as f32).max(0.1).log10();
+        (chart_bottom - ((log_days - log_min) / (log_max - log_min)) * chart_h).clamp(chart_top, chart_bottom)
+    };
+
+    let n = by_category.len();
+    let slot_w = (chart_right - chart_left) / n as f32;
+    let box_w = (slot_w * 0.5).min(80.0);
+
+    for (i, (cat, values, unmerged)) in by_category.iter().enumerate() {
+        let cx = chart_left + (i as f32 + 0.5) * slot_w;
+        let color = category_color(scheme, cat);
+
+        if !values.is_empty() {
+            let median = median_f64(values);
+            let q1 = quartile_interp(values, 25.0);
+            let q3 = quartile_interp(values, 75.0);
+            let iqr = q3 - q1;
+            let lo_fence = q1 - 1.5 * iqr;
+            let hi_fence = q3 + 1.5 * iqr;
+            let whisker_lo = values.iter().cloned().filter(|&v| v >= lo_fence).fold(f64::MAX, f64::min);
+            let whisker_hi = values.iter().cloned().filter(|&v| v <= hi_fence).fold(f64::MIN, f64::max);
+
+            let y_q1 = day_to_y(q1);
+            let y_q3 = day_to_y(q3);
+            let y_med = day_to_y(median);
+            let y_whisker_lo = day_to_y(whisker_lo);
+            let y_whisker_hi = day_to_y(whisker_hi);
+
+            // Whiskers: vertical stem from the box edge out to the furthest
+            // in-fence point, capped with a short horizontal tick.
+            canvas.line(cx, y_whisker_lo, cx, y_q1, dim(scheme), 1.5);
+            canvas.line(cx, y_q3, cx, y_whisker_hi, dim(scheme), 1.5);
+            canvas.line(cx - box_w * 0.25, y_whisker_lo, cx + box_w * 0.25, y_whisker_lo, dim(scheme), 1.5);
+            canvas.line(cx - box_w * 0.25, y_whisker_hi, cx + box_w * 0.25, y_whisker_hi, dim(scheme), 1.5);
+
+            // Box: Q1-Q3
+            canvas.rect_alpha(cx - box_w / 2.0, y_q3, box_w, (y_q1 - y_q3).max(1.0), color, 0.55);
+            canvas.line(cx - box_w / 2.0, y_q3, cx + box_w / 2.0, y_q3, color, 1.5);
+            canvas.line(cx - box_w / 2.0, y_q1, cx + box_w / 2.0, y_q1, color, 1.5);
+            canvas.line(cx - box_w / 2.0, y_q3, cx - box_w / 2.0, y_q1, color, 1.5);
+            canvas.line(cx + box_w / 2.0, y_q3, cx + box_w / 2.0, y_q1, color, 1.5);
+
+            // Median line
+            canvas.line(cx - box_w / 2.0, y_med, cx + box_w / 2.0, y_med, white(scheme), 2.0);
+
+            // Outliers beyond the whiskers
+            for &v in values.iter().filter(|&&v| v < whisker_lo || v > whisker_hi) {
+                canvas.circle(cx, day_to_y(v), 3.0, color);
+            }
+
+            let label = format!("n={} med={:.1}d", values.len(), median);
+            let lw = canvas.measure_text(&label, 11.0);
+            canvas.text(&label, cx - lw / 2.0, chart_bottom + 20.0, 11.0, light(scheme));
+        }
+
+        if *unmerged > 0 {
+            let label = format!("{} unmerged", unmerged);
+            let lw = canvas.measure_text(&label, 11.0);
+            canvas.text(&label, cx - lw / 2.0, chart_top - 8.0, 11.0, magenta(scheme));
+        }
+
+        let cat_w = canvas.measure_text(cat, 13.0);
+        canvas.text(cat, cx - cat_w / 2.0, chart_bottom + 40.0, 13.0, white(scheme));
+    }
+
+    // Y-axis labels (same thresholds as the scatter chart)
+    for &days in &[0.1f32, 1.0, 7.0, 30.0, 100.0, 365.0] {
+        let log_d = days.log10();
+        let y = chart_bottom - ((log_d - log_min) / (log_max - log_min)) * chart_h;
+        if y > chart_top && y < chart_bottom {
+            let label = if days < 1.0 { format!("{:.1}d", days) } else { format!("{}d", days as u32) };
+            canvas.text(&label, 40.0, y + 4.0, 10.0, dim(scheme));
         }
     }
 
-    text.draw_text(&mut pixmap, "commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
-    save_chart(&pixmap, dir, "04_merge_scatter.png")
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "04b_latency_boxplot")
 }
 
 // ============================================================
@@ -620,20 +1177,25 @@ pub fn render_merge_latency_scatter(
 // ============================================================
 pub fn render_release_cadence(
     wm: &ChangeFlowMetrics,
-    text: &TextRenderer,
+    canvas: &mut dyn Canvas,
     dir: &Path,
+    // Release intervals/distribution carry no per-entry date to filter on.
+    _window: DateWindow,
+    scheme: ColorScheme,
+    // This chart has no multi-repo lane layout; only the release heatmap and
+    // velocity charts stack lanes.
+    _multi: Option<&MultiRepo>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut pixmap = Pixmap::new(WIDTH, HEIGHT).unwrap();
-    pixmap.fill(bg());
+    canvas.fill_background(bg(scheme));
 
-    text.draw_text(&mut pixmap, "Release Cadence & Interval Distribution", 40.0, 50.0, 28.0, white());
-    text.draw_text(&mut pixmap, "How predictable is the release rhythm? Green dots within the band are healthy intervals.", 40.0, 78.0, 13.0, dim());
-    text.draw_text(&mut pixmap, "Outlier red dots suggest disruptions. A high CV (>0.5) means unpredictable delivery timing.", 40.0, 94.0, 13.0, dim());
+    canvas.text("Release Cadence & Interval Distribution", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("How predictable is the release rhythm? Green dots within the band are healthy intervals.", 40.0, 78.0, 13.0, dim(scheme));
+    canvas.text("Outlier red dots suggest disruptions. A high CV (>0.5) means unpredictable delivery timing.", 40.0, 94.0, 13.0, dim(scheme));
 
     let intervals = &wm.release_intervals;
     if intervals.is_empty() {
-        text.draw_text(&mut pixmap, "Not enough releases for analysis", 40.0, 130.0, 18.0, dim());
-        save_chart(&pixmap, dir, "05_release_cadence.png")?;
+        canvas.text("Not enough releases for analysis", 40.0, 130.0, 18.0, dim(scheme));
+        canvas.save(dir, "05_release_cadence")?;
         return Ok(());
     }
 
@@ -642,7 +1204,7 @@ pub fn render_release_cadence(
         wm.release_interval_mean, wm.release_interval_median,
         wm.release_interval_cv, wm.release_interval_longest_gap
     );
-    text.draw_text(&mut pixmap, &stats, 40.0, 115.0, 16.0, light());
+    canvas.text(&stats, 40.0, 115.0, 16.0, light(scheme));
 
     // Lollipop chart (left 70% of width)
     let lollipop_right = WIDTH as f32 * 0.68;
@@ -664,12 +1226,12 @@ pub fn render_release_cadence(
     let band_hi = ((mean + stdev) / max_days) as f32;
     let band_y_top = chart_bottom - band_hi.min(1.0) * chart_h;
     let band_y_bot = chart_bottom - band_lo * chart_h;
-    fill_rect_alpha(&mut pixmap, chart_left, band_y_top, chart_w, band_y_bot - band_y_top,
+    canvas.rect_alpha(chart_left, band_y_top, chart_w, band_y_bot - band_y_top,
         Color::from_rgba8(76, 175, 80, 255), 0.08);
     // Dashed border lines for the healthy band
-    draw_dashed_line(&mut pixmap, chart_left, band_y_top, chart_left + chart_w, band_y_top,
+    canvas.dashed_line(chart_left, band_y_top, chart_left + chart_w, band_y_top,
         Color::from_rgba8(76, 175, 80, 100), 1.0, 6.0);
-    draw_dashed_line(&mut pixmap, chart_left, band_y_bot, chart_left + chart_w, band_y_bot,
+    canvas.dashed_line(chart_left, band_y_bot, chart_left + chart_w, band_y_bot,
         Color::from_rgba8(76, 175, 80, 100), 1.0, 6.0);
 
     // Draw lollipops
@@ -681,54 +1243,264 @@ pub fn render_release_cadence(
         let y = chart_bottom - h_frac * chart_h;
 
         // Stick
-        draw_line(&mut pixmap, x, chart_bottom, x, y, Color::from_rgba8(100, 100, 100, 200), 1.5);
+        canvas.line(x, chart_bottom, x, y, Color::from_rgba8(100, 100, 100, 200), 1.5);
 
         // Dot colored by distance from mean
         let dist = (interval.days_since_previous - mean).abs();
         let color = if dist < stdev {
-            heat_color(0.0) // green
+            heat_color(scheme, 0.0) // green
         } else if dist < stdev * 2.0 {
-            heat_color(0.5) // yellow
+            heat_color(scheme, 0.5) // yellow
         } else {
-            heat_color(1.0) // red
+            heat_color(scheme, 1.0) // red
         };
-        fill_circle(&mut pixmap, x, y, 4.0, color);
+        canvas.circle(x, y, 4.0, color);
     }
 
     // Mean line
     let mean_y = chart_bottom - (mean / max_days) as f32 * chart_h;
-    draw_dashed_line(&mut pixmap, chart_left, mean_y, lollipop_right, mean_y,
+    canvas.dashed_line(chart_left, mean_y, lollipop_right, mean_y,
         Color::from_rgba8(255, 255, 255, 150), 1.0, 6.0);
-    text.draw_text(&mut pixmap, &format!("mean={:.0}d", mean), lollipop_right - 100.0, mean_y - 5.0, 10.0, light());
+    canvas.text(&format!("mean={:.0}d", mean), lollipop_right - 100.0, mean_y - 5.0, 10.0, light(scheme));
 
     // Histogram sidebar (right 28% of width)
     let hist_left = WIDTH as f32 * 0.72;
     let hist_right = WIDTH as f32 - 40.0;
     let hist_w = hist_right - hist_left;
 
-    text.draw_text(&mut pixmap, "Distribution", hist_left, chart_top - 5.0, 16.0, white());
+    canvas.text("Distribution", hist_left, chart_top - 5.0, 16.0, white(scheme));
+    canvas.text("Box plot (IQR)", hist_left, chart_top + 14.0, 11.0, dim(scheme));
+
+    // Box-and-whisker summary of the raw intervals: a five-number view (Q1,
+    // median, Q3 by linear interpolation, whiskers at the most extreme points
+    // within 1.5x IQR of the box, outliers beyond as dots) that survives
+    // skewed cadence data better than the mean±stdev band on the left.
+    let days: Vec<f64> = intervals.iter().map(|r| r.days_since_previous).collect();
+    let q1 = quartile_interp(&days, 25.0);
+    let box_median = median_f64(&days);
+    let q3 = quartile_interp(&days, 75.0);
+    let iqr = q3 - q1;
+    let lo_fence = q1 - 1.5 * iqr;
+    let hi_fence = q3 + 1.5 * iqr;
+    let whisker_lo = days.iter().cloned().filter(|&v| v >= lo_fence).fold(f64::MAX, f64::min);
+    let whisker_hi = days.iter().cloned().filter(|&v| v <= hi_fence).fold(f64::MIN, f64::max);
+
+    let box_top = chart_top + 20.0;
+    let box_h = 90.0f32;
+    let box_mid_y = box_top + box_h / 2.0;
+    let box_axis_max = days.iter().cloned().fold(0.0f64, f64::max).max(whisker_hi).max(1.0);
+    let day_to_x = |d: f64| -> f32 { hist_left + (d / box_axis_max).clamp(0.0, 1.0) as f32 * hist_w };
+    let box_color = heat_color(scheme, 0.0);
+
+    canvas.line(day_to_x(whisker_lo), box_mid_y, day_to_x(q1), box_mid_y, dim(scheme), 1.5);
+    canvas.line(day_to_x(q3), box_mid_y, day_to_x(whisker_hi), box_mid_y, dim(scheme), 1.5);
+    canvas.line(day_to_x(whisker_lo), box_mid_y - box_h * 0.15, day_to_x(whisker_lo), box_mid_y + box_h * 0.15, dim(scheme), 1.5);
+    canvas.line(day_to_x(whisker_hi), box_mid_y - box_h * 0.15, day_to_x(whisker_hi), box_mid_y + box_h * 0.15, dim(scheme), 1.5);
+
+    canvas.rect_alpha(day_to_x(q1), box_mid_y - box_h * 0.3, (day_to_x(q3) - day_to_x(q1)).max(1.0), box_h * 0.6, box_color, 0.55);
+    canvas.line(day_to_x(q1), box_mid_y - box_h * 0.3, day_to_x(q1), box_mid_y + box_h * 0.3, box_color, 1.5);
+    canvas.line(day_to_x(q3), box_mid_y - box_h * 0.3, day_to_x(q3), box_mid_y + box_h * 0.3, box_color, 1.5);
+    canvas.line(day_to_x(box_median), box_mid_y - box_h * 0.3, day_to_x(box_median), box_mid_y + box_h * 0.3, white(scheme), 2.0);
+
+    for &v in days.iter().filter(|&&v| v < whisker_lo || v > whisker_hi) {
+        canvas.circle(day_to_x(v), box_mid_y, 3.0, box_color);
+    }
+
+    for (label, d) in [("Q1", q1), ("med", box_median), ("Q3", q3)] {
+        let text = format!("{} {:.0}d", label, d);
+        let tw = canvas.measure_text(&text, 10.0);
+        canvas.text(&text, day_to_x(d) - tw / 2.0, box_mid_y + box_h * 0.3 + 16.0, 10.0, light(scheme));
+    }
+
+    let hist_top = box_top + box_h + 50.0;
 
     let bins = &wm.release_interval_distribution;
     if !bins.is_empty() {
         let max_bin = bins.iter().map(|b| b.count).max().unwrap_or(1).max(1);
-        let bin_h = 40.0f32;
-        let bin_gap = 8.0f32;
+        let bin_h = 30.0f32;
+        let bin_gap = 6.0f32;
+        let bar_x = hist_left + 70.0;
 
         for (i, bin) in bins.iter().enumerate() {
-            let y = chart_top + 20.0 + i as f32 * (bin_h + bin_gap);
+            let y = hist_top + i as f32 * (bin_h + bin_gap);
+            if y + bin_h > chart_bottom { break; }
             let w = (bin.count as f32 / max_bin as f32) * hist_w * 0.7;
 
-            text.draw_text(&mut pixmap, &bin.label, hist_left, y + bin_h / 2.0 + 4.0, 12.0, light());
+            canvas.text(&bin.label, hist_left, y + bin_h / 2.0 + 4.0, 12.0, light(scheme));
+
+            canvas.rect(bar_x, y, w, bin_h, Color::from_rgba8(66, 133, 244, 200));
+
+            canvas.text(&bin.count.to_string(), bar_x + w + 8.0, y + bin_h / 2.0 + 4.0, 12.0, dim(scheme));
+        }
+
+        // Gaussian KDE overlay (Silverman's rule bandwidth): a smooth fitted
+        // density curve across the same bins, so multimodality the coarse
+        // bars hide is still visible. Self-contained like the boxplot above;
+        // skipped for n < 3 where a bandwidth estimate isn't meaningful.
+        let n = days.len() as f64;
+        if n >= 3.0 {
+            let days_mean = days.iter().sum::<f64>() / n;
+            let variance = days.iter().map(|d| (d - days_mean).powi(2)).sum::<f64>() / (n - 1.0);
+            let sigma = variance.sqrt();
+            let bandwidth = if sigma > 0.0 { 1.06 * sigma * n.powf(-0.2) } else { 1.0 };
+
+            let phi = |t: f64| (-t * t / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt();
+            let kde = |x: f64| -> f64 {
+                days.iter().map(|&xi| phi((x - xi) / bandwidth)).sum::<f64>() / (n * bandwidth)
+            };
+
+            let n_bins = bins.len();
+            let bin_width_days = box_axis_max / n_bins as f64;
+            let densities: Vec<f64> = (0..n_bins).map(|i| kde((i as f64 + 0.5) * bin_width_days)).collect();
+            let max_density = densities.iter().cloned().fold(0.0f64, f64::max).max(1e-9);
+
+            let mut prev: Option<(f32, f32)> = None;
+            for (i, &density) in densities.iter().enumerate() {
+                let y = hist_top + i as f32 * (bin_h + bin_gap) + bin_h / 2.0;
+                if y > chart_bottom { break; }
+                let x = bar_x + (density / max_density) as f32 * hist_w * 0.7;
+                if let Some((px, py)) = prev {
+                    canvas.line(px, py, x, y, Color::from_rgba8(255, 183, 0, 230), 2.0);
+                }
+                canvas.circle(x, y, 2.5, Color::from_rgba8(255, 183, 0, 230));
+                prev = Some((x, y));
+            }
+        }
+    }
+
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "05_release_cadence")
+}
+
+/// Draw one frame of the release-cadence lollipop chart using only the first
+/// `intervals.len()` releases out of `total` — the mean, healthy band, and
+/// per-dot coloring are all recomputed from just that prefix (not the
+/// whole-history `wm.release_interval_*` fields `render_release_cadence`
+/// uses), so `render_release_cadence_animated` can show cadence stabilize or
+/// destabilize as releases accrue. Deliberately a stripped-down version of
+/// the static chart — lollipop/band/mean only, no histogram sidebar.
+fn render_release_cadence_frame(intervals: &[ReleaseInterval], total: usize, canvas: &mut dyn Canvas, scheme: ColorScheme) {
+    canvas.fill_background(bg(scheme));
+    canvas.text("Release Cadence & Interval Distribution", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text(&format!("Release {} of {}", intervals.len(), total), 40.0, 78.0, 16.0, dim(scheme));
+
+    let days: Vec<f64> = intervals.iter().map(|r| r.days_since_previous).collect();
+    let n = days.len() as f64;
+    let mean = days.iter().sum::<f64>() / n;
+    let stdev = if n >= 2.0 {
+        (days.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+    } else {
+        mean * 0.3
+    };
+
+    let chart_left = 80.0f32;
+    let chart_top = 150.0f32;
+    let chart_bottom = 950.0f32;
+    let chart_h = chart_bottom - chart_top;
+    let chart_right = WIDTH as f32 - 80.0;
+    let chart_w = chart_right - chart_left;
+
+    let max_days = days.iter().cloned().fold(0.0f64, f64::max).max(1.0);
+    let stick_gap = (chart_w / intervals.len() as f32).min(20.0);
+
+    let band_lo = ((mean - stdev).max(0.0) / max_days) as f32;
+    let band_hi = ((mean + stdev) / max_days) as f32;
+    let band_y_top = chart_bottom - band_hi.min(1.0) * chart_h;
+    let band_y_bot = chart_bottom - band_lo * chart_h;
+    canvas.rect_alpha(chart_left, band_y_top, chart_w, band_y_bot - band_y_top,
+        Color::from_rgba8(76, 175, 80, 255), 0.08);
+    canvas.dashed_line(chart_left, band_y_top, chart_left + chart_w, band_y_top,
+        Color::from_rgba8(76, 175, 80, 100), 1.0, 6.0);
+    canvas.dashed_line(chart_left, band_y_bot, chart_left + chart_w, band_y_bot,
+        Color::from_rgba8(76, 175, 80, 100), 1.0, 6.0);
+
+    for (i, interval) in intervals.iter().enumerate() {
+        let x = chart_left + (i as f32 + 0.5) * stick_gap;
+        if x > chart_right { break; }
+
+        let h_frac = (interval.days_since_previous / max_days) as f32;
+        let y = chart_bottom - h_frac * chart_h;
+
+        canvas.line(x, chart_bottom, x, y, Color::from_rgba8(100, 100, 100, 200), 1.5);
+
+        let dist = (interval.days_since_previous - mean).abs();
+        let color = if dist < stdev {
+            heat_color(scheme, 0.0)
+        } else if dist < stdev * 2.0 {
+            heat_color(scheme, 0.5)
+        } else {
+            heat_color(scheme, 1.0)
+        };
+        canvas.circle(x, y, 4.0, color);
+    }
+
+    let mean_y = chart_bottom - (mean / max_days) as f32 * chart_h;
+    canvas.dashed_line(chart_left, mean_y, chart_right, mean_y,
+        Color::from_rgba8(255, 255, 255, 150), 1.0, 6.0);
+    canvas.text(&format!("mean={:.0}d", mean), chart_right - 100.0, mean_y - 5.0, 10.0, light(scheme));
+
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+}
 
-            let bar_x = hist_left + 70.0;
-            fill_rect(&mut pixmap, bar_x, y, w, bin_h, Color::from_rgba8(66, 133, 244, 200));
+/// Render the release-cadence lollipop chart as an animated GIF: one frame
+/// per release added (subsampled down to `frame_count` frames when there are
+/// more releases than that), so viewers can watch cadence stabilize or
+/// destabilize over the project's history. Frames render in parallel on the
+/// existing rayon pool exactly like `render_gif`'s video frames, then share
+/// one global palette quantized across all of them.
+pub fn render_release_cadence_animated(
+    wm: &ChangeFlowMetrics,
+    dir: &Path,
+    scheme: ColorScheme,
+    frame_count: u32,
+    fps: u32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let intervals = &wm.release_intervals;
+    if intervals.is_empty() {
+        eprintln!("No release intervals to animate — skipping");
+        return Ok(());
+    }
 
-            text.draw_text(&mut pixmap, &bin.count.to_string(), bar_x + w + 8.0, y + bin_h / 2.0 + 4.0, 12.0, dim());
+    std::fs::create_dir_all(dir)?;
+    let total = intervals.len();
+    let frame_count = frame_count.max(1).min(total as u32);
+
+    eprintln!("Rendering release cadence animation ({} frames)...", frame_count);
+
+    let rgba_frames: Vec<Vec<u8>> = (0..frame_count)
+        .into_par_iter()
+        .map(|i| {
+            let progress = (i + 1) as f32 / frame_count as f32;
+            let k = ((progress * total as f32).ceil() as usize).clamp(1, total);
+            let mut canvas = PixmapCanvas::new(WIDTH, HEIGHT, false);
+            render_release_cadence_frame(&intervals[..k], total, &mut canvas, scheme);
+            canvas.rgba().to_vec()
+        })
+        .collect();
+
+    let mut samples: Vec<[u8; 3]> = Vec::new();
+    for rgba in &rgba_frames {
+        for px in rgba.chunks_exact(4).step_by(17) {
+            samples.push([px[0], px[1], px[2]]);
         }
     }
+    let mut palette = Palette::build(samples.iter().copied(), 256);
+    palette.refine_kmeans(&samples, 4);
+
+    let path = dir.join("05_release_cadence_animated.gif");
+    let file = std::fs::File::create(&path)?;
+    let writer = std::io::BufWriter::new(file);
+    let mut encoder = GifEncoder::new(writer, WIDTH as u16, HEIGHT as u16, &palette, Some(0))?;
+
+    let delay_cs = (100.0 / f64::from(fps.max(1))).round().max(1.0) as u16;
+    for rgba in &rgba_frames {
+        let indices = dither_frame(rgba, WIDTH, HEIGHT, &palette);
+        encoder.write_frame(&indices, delay_cs)?;
+    }
+    encoder.finish()?;
 
-    text.draw_text(&mut pixmap, "commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
-    save_chart(&pixmap, dir, "05_release_cadence.png")
+    eprintln!("  Wrote {:?}", path);
+    Ok(())
 }
 
 // ============================================================
@@ -736,23 +1508,28 @@ pub fn render_release_cadence(
 // ============================================================
 pub fn render_work_disposition_donut(
     wm: &ChangeFlowMetrics,
-    text: &TextRenderer,
+    canvas: &mut dyn Canvas,
     dir: &Path,
+    // Work disposition totals carry no per-entry date to filter on.
+    _window: DateWindow,
+    scheme: ColorScheme,
+    // This chart has no multi-repo lane layout; only the release heatmap and
+    // velocity charts stack lanes.
+    _multi: Option<&MultiRepo>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let mut pixmap = Pixmap::new(WIDTH, HEIGHT).unwrap();
-    pixmap.fill(bg());
+    canvas.fill_background(bg(scheme));
 
-    text.draw_text(&mut pixmap, "Work Disposition", 40.0, 50.0, 28.0, white());
-    text.draw_text(&mut pixmap, "What proportion of work ships quickly vs. slowly vs. not at all? A healthy codebase shows mostly", 40.0, 78.0, 13.0, dim());
-    text.draw_text(&mut pixmap, "green (fast-merged). Large yellow or red segments indicate slow review cycles or abandoned work.", 40.0, 94.0, 13.0, dim());
+    canvas.text("Work Disposition", 40.0, 50.0, 28.0, white(scheme));
+    canvas.text("What proportion of work ships quickly vs. slowly vs. not at all? A healthy codebase shows mostly", 40.0, 78.0, 13.0, dim(scheme));
+    canvas.text("green (fast-merged). Large yellow or red segments indicate slow review cycles or abandoned work.", 40.0, 94.0, 13.0, dim(scheme));
 
     let wd = &wm.work_disposition;
     let total_lines = wd.fast_merged_lines + wd.slow_merged_lines + wd.unmerged_lines;
     let total_commits = wd.fast_merged_commits + wd.slow_merged_commits + wd.unmerged_commits;
 
     if total_lines == 0 {
-        text.draw_text(&mut pixmap, "No disposition data", 40.0, 130.0, 18.0, dim());
-        save_chart(&pixmap, dir, "06_work_disposition.png")?;
+        canvas.text("No disposition data", 40.0, 130.0, 18.0, dim(scheme));
+        canvas.save(dir, "06_work_disposition")?;
         return Ok(());
     }
 
@@ -763,38 +1540,29 @@ pub fn render_work_disposition_donut(
     let mid_r = 200.0f32;
     let inner_r = 130.0f32;
 
-    // Inner ring: fast/slow/unmerged by lines
+    // Inner ring: fast/slow/unmerged by lines, using the same green/yellow/red
+    // (or colorblind-safe equivalent) gradient as the other charts' heat_color.
     let segments_inner = [
-        ("Fast merged (<7d)", wd.fast_merged_lines, Color::from_rgba8(76, 175, 80, 230)),
-        ("Slow merged (>7d)", wd.slow_merged_lines, Color::from_rgba8(255, 193, 7, 230)),
-        ("Unmerged", wd.unmerged_lines, Color::from_rgba8(244, 67, 54, 230)),
+        ("Fast merged (<7d)", "fast", wd.fast_merged_lines, heat_color(scheme, 0.0)),
+        ("Slow merged (>7d)", "slow", wd.slow_merged_lines, heat_color(scheme, 0.5)),
+        ("Unmerged", "unmerged", wd.unmerged_lines, heat_color(scheme, 1.0)),
     ];
 
     let total_f = total_lines as f64;
     let mut angle = -std::f64::consts::FRAC_PI_2; // start at top
 
     // Draw inner ring arcs
-    for &(_, lines, color) in &segments_inner {
+    for &(_, _speed, lines, color) in &segments_inner {
         if lines == 0 { continue; }
         let sweep = (lines as f64 / total_f) * std::f64::consts::TAU;
-        draw_arc_filled(&mut pixmap, cx, cy, inner_r, mid_r, angle as f32, sweep as f32, color);
+        canvas.arc_filled(cx, cy, inner_r, mid_r, angle as f32, sweep as f32, color);
         angle += sweep;
     }
 
     // Outer ring: subdivide by category within each merge-speed segment
     angle = -std::f64::consts::FRAC_PI_2;
-    for &(_, lines, base_color) in &segments_inner {
+    for &(_, speed_match, lines, base_color) in &segments_inner {
         if lines == 0 { continue; }
-        let speed = match base_color.green() as u32 {
-            175 => "fast",
-            193 => "slow",
-            _ => "unmerged",
-        };
-        let speed_match = match speed {
-            "fast" => "fast",
-            "slow" => "slow",
-            _ => "unmerged",
-        };
 
         // Get sub-segments for this speed
         let sub_segs: Vec<_> = wd.segments.iter()
@@ -804,15 +1572,15 @@ pub fn render_work_disposition_donut(
         let speed_sweep = (lines as f64 / total_f) * std::f64::consts::TAU;
 
         if sub_segs.is_empty() {
-            draw_arc_filled(&mut pixmap, cx, cy, mid_r + 4.0, outer_r, angle as f32, speed_sweep as f32, base_color);
+            canvas.arc_filled(cx, cy, mid_r + 4.0, outer_r, angle as f32, speed_sweep as f32, base_color);
             angle += speed_sweep;
         } else {
             let speed_total: u32 = sub_segs.iter().map(|s| s.lines_changed).sum();
             let speed_total = speed_total.max(1);
             for seg in &sub_segs {
                 let sub_sweep = (seg.lines_changed as f64 / speed_total as f64) * speed_sweep;
-                let color = category_color(&seg.category);
-                draw_arc_filled(&mut pixmap, cx, cy, mid_r + 4.0, outer_r, angle as f32, sub_sweep as f32, color);
+                let color = category_color(scheme, &seg.category);
+                canvas.arc_filled(cx, cy, mid_r + 4.0, outer_r, angle as f32, sub_sweep as f32, color);
                 angle += sub_sweep;
             }
         }
@@ -821,41 +1589,41 @@ pub fn render_work_disposition_donut(
     // Center text
     let total_label = format!("{} commits", total_commits);
     let lines_label = format!("{} lines", total_lines);
-    let tw1 = text.measure_text(&total_label, 18.0);
-    let tw2 = text.measure_text(&lines_label, 14.0);
-    text.draw_text(&mut pixmap, &total_label, cx - tw1 / 2.0, cy - 5.0, 18.0, white());
-    text.draw_text(&mut pixmap, &lines_label, cx - tw2 / 2.0, cy + 18.0, 14.0, light());
+    let tw1 = canvas.measure_text(&total_label, 18.0);
+    let tw2 = canvas.measure_text(&lines_label, 14.0);
+    canvas.text(&total_label, cx - tw1 / 2.0, cy - 5.0, 18.0, white(scheme));
+    canvas.text(&lines_label, cx - tw2 / 2.0, cy + 18.0, 14.0, light(scheme));
 
     // Right panel: detail table
     let table_left = 820.0f32;
     let mut ty = 120.0f32;
 
-    text.draw_text(&mut pixmap, "Breakdown", table_left, ty, 20.0, white());
+    canvas.text("Breakdown", table_left, ty, 20.0, white(scheme));
     ty += 40.0;
 
     // Header
-    text.draw_text(&mut pixmap, "Category", table_left, ty, 13.0, dim());
-    text.draw_text(&mut pixmap, "Speed", table_left + 160.0, ty, 13.0, dim());
-    text.draw_text(&mut pixmap, "Lines", table_left + 290.0, ty, 13.0, dim());
-    text.draw_text(&mut pixmap, "Commits", table_left + 390.0, ty, 13.0, dim());
-    text.draw_text(&mut pixmap, "%", table_left + 490.0, ty, 13.0, dim());
+    canvas.text("Category", table_left, ty, 13.0, dim(scheme));
+    canvas.text("Speed", table_left + 160.0, ty, 13.0, dim(scheme));
+    canvas.text("Lines", table_left + 290.0, ty, 13.0, dim(scheme));
+    canvas.text("Commits", table_left + 390.0, ty, 13.0, dim(scheme));
+    canvas.text("%", table_left + 490.0, ty, 13.0, dim(scheme));
     ty += 25.0;
 
-    draw_line(&mut pixmap, table_left, ty - 5.0, WIDTH as f32 - 40.0, ty - 5.0,
+    canvas.line(table_left, ty - 5.0, WIDTH as f32 - 40.0, ty - 5.0,
         Color::from_rgba8(60, 60, 60, 255), 1.0);
 
     for seg in &wd.segments {
         if ty > HEIGHT as f32 - 60.0 { break; }
         let pct = seg.lines_changed as f64 / total_f * 100.0;
 
-        let cat_color = category_color(&seg.category);
-        fill_rect(&mut pixmap, table_left - 18.0, ty - 10.0, 10.0, 10.0, cat_color);
+        let cat_color = category_color(scheme, &seg.category);
+        canvas.rect(table_left - 18.0, ty - 10.0, 10.0, 10.0, cat_color);
 
-        text.draw_text(&mut pixmap, &seg.category, table_left, ty, 12.0, light());
-        text.draw_text(&mut pixmap, &seg.merge_speed, table_left + 160.0, ty, 12.0, light());
-        text.draw_text(&mut pixmap, &seg.lines_changed.to_string(), table_left + 290.0, ty, 12.0, light());
-        text.draw_text(&mut pixmap, &seg.commit_count.to_string(), table_left + 390.0, ty, 12.0, light());
-        text.draw_text(&mut pixmap, &format!("{:.1}%", pct), table_left + 490.0, ty, 12.0, light());
+        canvas.text(&seg.category, table_left, ty, 12.0, light(scheme));
+        canvas.text(&seg.merge_speed, table_left + 160.0, ty, 12.0, light(scheme));
+        canvas.text(&seg.lines_changed.to_string(), table_left + 290.0, ty, 12.0, light(scheme));
+        canvas.text(&seg.commit_count.to_string(), table_left + 390.0, ty, 12.0, light(scheme));
+        canvas.text(&format!("{:.1}%", pct), table_left + 490.0, ty, 12.0, light(scheme));
         ty += 22.0;
     }
 
@@ -865,71 +1633,39 @@ pub fn render_work_disposition_donut(
     let slow_pct = wd.slow_merged_lines as f64 / total_f * 100.0;
     let unmerged_pct = wd.unmerged_lines as f64 / total_f * 100.0;
 
-    fill_rect(&mut pixmap, table_left - 18.0, ty - 10.0, 10.0, 10.0, Color::from_rgba8(76, 175, 80, 230));
-    text.draw_text(&mut pixmap, &format!("Fast merged (<7d): {:.1}%", fast_pct), table_left, ty, 14.0, light());
+    canvas.rect(table_left - 18.0, ty - 10.0, 10.0, 10.0, Color::from_rgba8(76, 175, 80, 230));
+    canvas.text(&format!("Fast merged (<7d): {:.1}%", fast_pct), table_left, ty, 14.0, light(scheme));
     ty += 25.0;
-    fill_rect(&mut pixmap, table_left - 18.0, ty - 10.0, 10.0, 10.0, Color::from_rgba8(255, 193, 7, 230));
-    text.draw_text(&mut pixmap, &format!("Slow merged (>7d): {:.1}%", slow_pct), table_left, ty, 14.0, light());
+    canvas.rect(table_left - 18.0, ty - 10.0, 10.0, 10.0, Color::from_rgba8(255, 193, 7, 230));
+    canvas.text(&format!("Slow merged (>7d): {:.1}%", slow_pct), table_left, ty, 14.0, light(scheme));
     ty += 25.0;
-    fill_rect(&mut pixmap, table_left - 18.0, ty - 10.0, 10.0, 10.0, Color::from_rgba8(244, 67, 54, 230));
-    text.draw_text(&mut pixmap, &format!("Unmerged: {:.1}%", unmerged_pct), table_left, ty, 14.0, light());
+    canvas.rect(table_left - 18.0, ty - 10.0, 10.0, 10.0, Color::from_rgba8(244, 67, 54, 230));
+    canvas.text(&format!("Unmerged: {:.1}%", unmerged_pct), table_left, ty, 14.0, light(scheme));
 
-    text.draw_text(&mut pixmap, "commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
-    save_chart(&pixmap, dir, "06_work_disposition.png")
-}
-
-/// Draw a filled arc segment (donut slice) using line segments approximation
-fn draw_arc_filled(
-    pixmap: &mut Pixmap,
-    cx: f32, cy: f32,
-    r_inner: f32, r_outer: f32,
-    start_angle: f32, sweep: f32,
-    color: Color,
-) {
-    if sweep.abs() < 0.001 { return; }
-
-    let steps = ((sweep.abs() * 50.0) as usize).max(4);
-    let step_angle = sweep / steps as f32;
-
-    let mut paint = Paint::default();
-    paint.set_color(color);
-    paint.anti_alias = true;
-
-    let mut pb = PathBuilder::new();
-
-    // Outer arc forward
-    let a0 = start_angle;
-    pb.move_to(cx + a0.cos() * r_outer, cy + a0.sin() * r_outer);
-    for i in 1..=steps {
-        let a = a0 + i as f32 * step_angle;
-        pb.line_to(cx + a.cos() * r_outer, cy + a.sin() * r_outer);
-    }
-
-    // Inner arc backward
-    for i in (0..=steps).rev() {
-        let a = a0 + i as f32 * step_angle;
-        pb.line_to(cx + a.cos() * r_inner, cy + a.sin() * r_inner);
-    }
-
-    pb.close();
-    if let Some(path) = pb.finish() {
-        pixmap.fill_path(&path, &paint, FillRule::Winding, Transform::identity(), None);
-    }
+    canvas.text("commit-viz", 40.0, HEIGHT as f32 - 20.0, 10.0, Color::from_rgba8(70, 70, 80, 255));
+    canvas.save(dir, "06_work_disposition")
 }
 
 /// Render all 6 change flow charts to the specified directory (parallel)
 pub fn render_all(
     wm: &ChangeFlowMetrics,
     dir: &Path,
+    window: DateWindow,
+    scheme: ColorScheme,
+    multi: Option<&MultiRepo>,
+    format: OutputFormat,
+    indexed_png: bool,
+    timeline: Option<&Timeline>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     std::fs::create_dir_all(dir)?;
 
-    // Each chart gets its own TextRenderer since they run in parallel threads
-    let renderers: Vec<(&str, Box<dyn Fn(&ChangeFlowMetrics, &TextRenderer, &Path) -> Result<(), Box<dyn std::error::Error>> + Send + Sync>)> = vec![
+    // Each chart gets its own Canvas since they run in parallel threads
+    let renderers: Vec<(&str, Box<dyn Fn(&ChangeFlowMetrics, &mut dyn Canvas, &Path, DateWindow, ColorScheme, Option<&MultiRepo>) -> Result<(), Box<dyn std::error::Error>> + Send + Sync>)> = vec![
         ("01_release_heatmap", Box::new(render_commit_to_release_heatmap)),
         ("02_branch_gantt", Box::new(render_branch_lifespan_gantt)),
         ("03_velocity_drought", Box::new(render_velocity_drought)),
         ("04_merge_scatter", Box::new(render_merge_latency_scatter)),
+        ("04b_latency_boxplot", Box::new(render_latency_boxplot)),
         ("05_release_cadence", Box::new(render_release_cadence)),
         ("06_work_disposition", Box::new(render_work_disposition_donut)),
     ];
@@ -937,8 +1673,13 @@ pub fn render_all(
     let results: Vec<Result<(), String>> = renderers
         .par_iter()
         .map(|(name, render_fn)| {
-            let text = TextRenderer::new();
-            render_fn(wm, &text, dir).map_err(|e| format!("Error rendering {}: {}", name, e))
+            let task_start = std::time::Instant::now();
+            let mut canvas = canvas::new_canvas(format, WIDTH, HEIGHT, indexed_png);
+            let result = render_fn(wm, &mut *canvas, dir, window, scheme, multi).map_err(|e| format!("Error rendering {}: {}", name, e));
+            if let Some(tl) = timeline {
+                tl.record_worker_task("Change flow charts", name, task_start, task_start.elapsed());
+            }
+            result
         })
         .collect();
 
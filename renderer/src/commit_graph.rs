@@ -0,0 +1,70 @@
+//! Topological generation numbers for the commit parent DAG: `gen(c) = 1 +
+//! max(gen(parents))`, with roots at 0, computed in one pass over
+//! `Commit::parents` via Kahn's algorithm. Generation numbers give O(1)
+//! ancestor pruning (if `gen(a) >= gen(b)` then `a` cannot be a proper
+//! ancestor of `b`) and a cheap graph-distance metric, used by `stats`'s
+//! integration-debt and stale-branch calculation. `describe`'s tag search
+//! and `layout`'s topo-order placement walk `Commit::parents` through their
+//! own indexes rather than this one.
+
+use crate::data::CollectedData;
+use std::collections::{HashMap, VecDeque};
+
+pub struct CommitGraph {
+    sha_to_idx: HashMap<String, usize>,
+    generation: Vec<u32>,
+}
+
+impl CommitGraph {
+    pub fn build(data: &CollectedData) -> CommitGraph {
+        let total = data.commits.len();
+        let sha_to_idx: HashMap<String, usize> =
+            data.commits.iter().enumerate().map(|(i, c)| (c.sha.clone(), i)).collect();
+
+        let mut pending_parents = vec![0u32; total];
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); total];
+        for (i, commit) in data.commits.iter().enumerate() {
+            for parent_sha in &commit.parents {
+                if let Some(&parent_idx) = sha_to_idx.get(parent_sha.as_str()) {
+                    pending_parents[i] += 1;
+                    children[parent_idx].push(i);
+                }
+            }
+        }
+
+        let mut generation = vec![0u32; total];
+        let mut ready: VecDeque<usize> = (0..total).filter(|&i| pending_parents[i] == 0).collect();
+        while let Some(index) = ready.pop_front() {
+            for &child in &children[index] {
+                generation[child] = generation[child].max(generation[index] + 1);
+                pending_parents[child] -= 1;
+                if pending_parents[child] == 0 {
+                    ready.push_back(child);
+                }
+            }
+        }
+
+        // Parents outside this (possibly `--since`-filtered) dataset are never
+        // counted above, so a commit whose parents are all missing starts
+        // ready at generation 0, the same as a real root. Only a cycle leaves
+        // commits permanently pending; they keep whatever generation their
+        // already-processed parents gave them (0 if none).
+        CommitGraph { sha_to_idx, generation }
+    }
+
+    pub fn generation(&self, index: usize) -> u32 {
+        self.generation[index]
+    }
+
+    pub fn index_of(&self, sha: &str) -> Option<usize> {
+        self.sha_to_idx.get(sha).copied()
+    }
+
+    /// O(1) ancestor pruning: `false` means `a` cannot possibly be a proper
+    /// ancestor of `b`. A `true` result is only necessary, not sufficient —
+    /// confirming actual ancestry still means walking parents.
+    #[allow(dead_code)]
+    pub fn could_be_ancestor(&self, a: usize, b: usize) -> bool {
+        self.generation[a] < self.generation[b]
+    }
+}
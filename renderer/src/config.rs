@@ -32,6 +32,20 @@ pub struct RenderConfig {
     #[arg(long, default_value = "network")]
     pub style: String,
 
+    /// Render commit rectangles and the Sacred Timeline with radial/linear
+    /// gradient shader fills instead of flat colors, for a volumetric rather
+    /// than flat-block look. Off by default.
+    #[arg(long)]
+    pub gradient_fills: bool,
+
+    /// Composite the gold glow elements (Sacred Timeline glow, tag lines,
+    /// merge-destination diamonds) with additive (`BlendMode::Plus`) instead
+    /// of the default `SourceOver`, so overlapping glow visibly brightens at
+    /// merge-dense points. Solid category fills are unaffected. Off by
+    /// default.
+    #[arg(long)]
+    pub additive_glow: bool,
+
     /// Output path for statistics report PNG
     #[arg(long)]
     pub report_output: Option<PathBuf>,
@@ -39,4 +53,245 @@ pub struct RenderConfig {
     /// Output directory for waste visualization PNGs
     #[arg(long)]
     pub waste_output_dir: Option<PathBuf>,
+
+    /// Video codec for `-c:v`. Accepts either a short family name (h264,
+    /// h265/hevc, vp9, av1, svt-av1), resolved to its ffmpeg encoder by
+    /// `resolve_codec`, or an already-qualified ffmpeg encoder name (e.g.
+    /// `libx264`), which passes through unchanged. The output container
+    /// (mp4/webm/mkv) is inferred by ffmpeg itself from `--output`'s file
+    /// extension, so pick a codec the target container supports (e.g. vp9
+    /// or av1 for `.webm`).
+    #[arg(long, default_value = "libx264")]
+    pub codec: String,
+
+    /// Constant rate factor / quality value passed to ffmpeg as -crf. Lower
+    /// is higher quality and larger output; the sensible range depends on
+    /// codec (0-51 for the x264/x265 family, 0-63 for vp9/av1).
+    #[arg(long, default_value_t = 23)]
+    pub crf: u32,
+
+    /// Encoder preset passed to ffmpeg as -preset, trading encode speed for
+    /// compression efficiency (e.g. ultrafast..veryslow for x264/x265).
+    /// Ignored by encoders that don't recognize a -preset flag.
+    #[arg(long, default_value = "fast")]
+    pub preset: String,
+
+    /// Pixel format of the encoded output stream (ffmpeg -pix_fmt)
+    #[arg(long, default_value = "yuv420p")]
+    pub pix_fmt: String,
+
+    /// Report elapsed time, frames done, and rolling FPS to stderr (only
+    /// meaningful with `--output -`, since stdout carries the frame stream)
+    #[arg(long)]
+    pub progress: bool,
+
+    /// Write an interactive HTML Gantt report of phase/frame timings
+    #[arg(long)]
+    pub timing_output: Option<PathBuf>,
+
+    /// Number of times an animated GIF output repeats (0 = loop forever).
+    /// Ignored for non-GIF output, which is always encoded through ffmpeg.
+    #[arg(long, default_value_t = 0)]
+    pub gif_loop: u16,
+
+    /// Palette size for animated GIF output (median-cut + k-means quantized)
+    #[arg(long, default_value_t = 256)]
+    pub gif_colors: u16,
+
+    /// Dump the raw per-frame render complexity metrics as a JSON array
+    #[arg(long)]
+    pub stats_json: Option<PathBuf>,
+
+    /// Cache rendered frames here, keyed by a content hash, so re-runs with
+    /// unchanged data/layout/style only re-render what actually changed.
+    /// Only honored by the default ffmpeg output path, not `--output -` or GIF.
+    #[arg(long)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Only include change flow chart data on or after this date (YYYY-MM-DD).
+    /// When neither `--since` nor `--until` is given, charts default to the
+    /// trailing 365 days rather than all of history.
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Only include change flow chart data on or before this date (YYYY-MM-DD).
+    #[arg(long)]
+    pub until: Option<String>,
+
+    /// Color scheme for change flow charts: green_red (default), viridis,
+    /// blues, or colorblind_safe (Okabe-Ito palette)
+    #[arg(long, default_value = "green_red")]
+    pub color_scheme: String,
+
+    /// Additional data JSON files for multi-repository comparison. When set,
+    /// the release-heatmap and velocity charts draw one stacked lane per
+    /// repository (primary `--input` first, then these, in order) instead
+    /// of a single-repo chart.
+    #[arg(long)]
+    pub repos: Vec<PathBuf>,
+
+    /// Render the release-heatmap, velocity/drought, release-cadence, and
+    /// work-disposition charts directly to the terminal as ANSI-colored
+    /// Unicode blocks instead of writing PNGs. Independent of
+    /// `--change-flow-dir` — both may be given at once.
+    #[arg(long)]
+    pub charts_stdout: bool,
+
+    /// Output format for change flow charts: png (default, tiny-skia raster)
+    /// or svg (scalable vector, for crisp embedding in docs/READMEs).
+    #[arg(long, default_value = "png")]
+    pub chart_format: String,
+
+    /// Quantize change flow chart PNGs to an 8-bit indexed palette
+    /// (median-cut + k-means, same as the GIF encoder) instead of full
+    /// 32-bit RGBA. The pixel data is stored as one uncompressed palette
+    /// index byte per pixel; no deflate compression is applied, so the file
+    /// is not necessarily smaller than the default PNG. Ignored for
+    /// `--chart-format svg`.
+    #[arg(long)]
+    pub chart_png_indexed: bool,
+
+    /// Render an animated GIF of the release cadence lollipop chart building
+    /// up one release at a time, to this directory (written as
+    /// `05_release_cadence_animated.gif`). Off by default.
+    #[arg(long)]
+    pub cadence_animation_dir: Option<PathBuf>,
+
+    /// Frame count for `--cadence-animation-dir`, capped at the number of
+    /// releases (at most one frame per release).
+    #[arg(long, default_value_t = 60)]
+    pub cadence_animation_frames: u32,
+
+    /// Frames per second for `--cadence-animation-dir`.
+    #[arg(long, default_value_t = 10)]
+    pub cadence_animation_fps: u32,
+
+    /// Output path for a GitHub-style commit calendar heatmap PNG (weeks as
+    /// columns, weekdays as rows). A year-at-a-glance view the lane-based
+    /// timeline layout can't show.
+    #[arg(long)]
+    pub calendar_heatmap_output: Option<PathBuf>,
+
+    /// Color ramp for `--calendar-heatmap-output`: green (default, GitHub's
+    /// own ramp), blue, or halloween.
+    #[arg(long, default_value = "green")]
+    pub calendar_heatmap_colors: String,
+
+    /// Restrict the visualization to commits on these branches (repeatable).
+    /// Empty (the default) means no branch filter. Applied before layout, so
+    /// it also prunes merges/branches referencing anything filtered out.
+    #[arg(long)]
+    pub branches: Vec<String>,
+
+    /// Which commit the detail footer (`draw_commit_footer`) spotlights:
+    /// "latest" (default, the newest visible commit each frame) or a
+    /// decimal commit index into the input data, to pin the footer to one
+    /// specific commit while driving an animation.
+    #[arg(long, default_value = "latest")]
+    pub inspector_commit: String,
+
+    /// Write statistics and change flow metrics as InfluxDB line protocol to
+    /// this file, so a repo's flow can be tracked across successive
+    /// collection runs in a time-series dashboard.
+    #[arg(long)]
+    pub influx_output: Option<PathBuf>,
+
+    /// POST the same InfluxDB line protocol directly to this
+    /// `http://host:port/write?db=...` endpoint. May be combined with
+    /// `--influx-output`.
+    #[arg(long)]
+    pub influx_url: Option<String>,
+
+    /// Hold a title card (repo name + commit date range) for this many
+    /// seconds before the animated commits begin. Unset (the default) skips
+    /// the intro entirely. Only honored by the default ffmpeg output path,
+    /// not `--output -` or GIF.
+    #[arg(long)]
+    pub intro_secs: Option<f32>,
+
+    /// Hold a closing summary card (total commits/branches/contributors)
+    /// for this many seconds after the animated commits finish. Unset (the
+    /// default) skips the outro entirely. Only honored by the default
+    /// ffmpeg output path, not `--output -` or GIF.
+    #[arg(long)]
+    pub outro_secs: Option<f32>,
+
+    /// Number of cross-fade frames between a title card and the adjacent
+    /// animated frame, for `--intro-secs`/`--outro-secs`. Also sets the
+    /// fade-in/fade-out length of `--annotations` cards. Ignored if none of
+    /// those is set.
+    #[arg(long, default_value_t = 15)]
+    pub fade_frames: u32,
+
+    /// Path to a JSON annotations file, narrating milestones as overlay
+    /// cards during playback. Each entry maps a commit (SHA prefix or tag)
+    /// or an absolute `time` to a `text` caption, e.g.
+    /// `{"annotations": [{"commit": "v1.0", "text": "First stable release"}]}`.
+    /// Resolved to the frame each target commit first becomes visible (or
+    /// the matching time-based frame) and faded in/out using
+    /// `--fade-frames`.
+    #[arg(long)]
+    pub annotations: Option<PathBuf>,
+
+    /// How long each annotation card holds at full opacity, in seconds,
+    /// once its fade-in completes. Ignored if `--annotations` isn't set.
+    #[arg(long, default_value_t = 3.0)]
+    pub annotation_hold_secs: f32,
+
+    /// Draw a small rolling frame-time line graph in a corner of each frame
+    /// (green under `--debug-overlay-threshold-ms`, red over), and print a
+    /// min/avg/p95/max frame-time and effective-fps summary to stderr once
+    /// rendering finishes. A lightweight profiling HUD for finding which
+    /// commits/layout densities are expensive to draw.
+    #[arg(long)]
+    pub debug_overlay: bool,
+
+    /// Frame-time threshold in milliseconds above which `--debug-overlay`
+    /// draws that frame's line segment in red instead of green.
+    #[arg(long, default_value_t = 33.0)]
+    pub debug_overlay_threshold_ms: f32,
+
+    /// With `--output -`, stream sixel-encoded frames directly to a
+    /// sixel-capable terminal instead of the YUV4MPEG2 stream ffmpeg expects,
+    /// for a quick in-terminal preview while iterating on layout/styling. No
+    /// ffmpeg process is spawned and no file is written.
+    #[arg(long)]
+    pub preview: bool,
+
+    /// How to order commits left-to-right: as-collected (default, trusts the
+    /// collector's order), date (strict chronological, but still deferred
+    /// until parents are placed), or topo (a real topological sort, so a
+    /// commit's x is always greater than all its ancestors'). See git's own
+    /// `--date-order`/`--topo-order` for the date/topo distinction.
+    #[arg(long, default_value = "as-collected")]
+    pub commit_order: String,
+
+    /// Classify branches as stale by commits-behind-mainline (generation
+    /// distance > 30) instead of the default wall-clock 30-day window. Off
+    /// by default since it changes `active_branches`/`stale_branches`
+    /// counts the stats overlay has always reported.
+    #[arg(long)]
+    pub stale_by_generation: bool,
+
+    /// Color theme for `--report-output`: dark (default), light, or
+    /// colorblind-safe (Okabe-Ito category palette, for the eight categories
+    /// to stay distinguishable for users who can't tell the default
+    /// blue/purple/cyan set apart).
+    #[arg(long, default_value = "dark")]
+    pub report_theme: String,
+
+    /// Output path for a multi-repository comparison report PNG, tiling a
+    /// category-distribution panel per repo (primary `--input` plus
+    /// `--repos`) side by side with an "all repos combined" aggregate panel.
+    #[arg(long)]
+    pub comparison_report_output: Option<PathBuf>,
+
+    /// Launch an interactive ratatui terminal browser over the statistics
+    /// (summary, category/author distribution toggled with c/a, release
+    /// cycle figures) instead of only writing image/video outputs. Runs
+    /// after the other output phases complete; independent of
+    /// `--charts-stdout`, which prints a static one-shot preview rather than
+    /// an interactive session.
+    #[arg(long)]
+    pub tui: bool,
 }
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::Deserialize;
 use std::fs;
 use std::path::Path;
@@ -26,6 +26,11 @@ pub struct Commit {
     pub sha: String,
     pub timestamp: DateTime<Utc>,
     pub branch: String,
+    /// SHAs of this commit's direct parents, for `CommitOrder::DateOrder`/
+    /// `TopoOrder` layout. Absent or pointing outside this dataset (e.g.
+    /// trimmed by `--since`) just means that parent is already "out".
+    #[serde(default)]
+    pub parents: Vec<String>,
     #[serde(default)]
     pub tags: Vec<String>,
     #[serde(default)]
@@ -36,16 +41,40 @@ pub struct Commit {
     pub files_changed: u32,
     #[serde(default = "default_category")]
     pub category: String,
+    /// File renames/moves this commit recorded, from the collector's git
+    /// rename detection. Drives `lineage::track_lineage` so a renamed file's
+    /// accumulated churn carries forward under its new path.
+    #[serde(default)]
+    pub renames: Vec<Rename>,
 }
 
 fn default_category() -> String {
     "other".to_string()
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Rename {
+    pub from: String,
+    pub to: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Merge {
     pub sha: String,
     pub from_branch: String,
+    /// Branch the merge landed on. Defaults to empty when the collector
+    /// doesn't supply it; `position_merges_dynamic` falls back to the merge
+    /// commit's own branch in that case.
+    #[serde(default)]
+    pub to_branch: String,
+    /// When the merge commit itself happened, for picking the right source
+    /// commit when several land on `from_branch` close together.
+    pub timestamp: Option<DateTime<Utc>>,
+    /// SHA of the tip commit being merged in, if the collector recorded it.
+    /// When present, `position_merges_dynamic` anchors the arc's source
+    /// directly on that commit instead of scanning `from_branch` backwards.
+    #[serde(default)]
+    pub merged_sha: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -68,6 +97,18 @@ pub struct AuthorEntry {
     pub commits: u32,
 }
 
+/// One point on a commits-over-time line: a date bucket (week or month,
+/// collector-formatted) and the commit count landing in it. Reused for both
+/// the repo-wide `Statistics::commit_timeline` and each author's series in
+/// `Statistics::author_timelines`.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct TimelineBucket {
+    #[serde(default)]
+    pub date: String,
+    #[serde(default)]
+    pub count: u32,
+}
+
 #[derive(Debug, Deserialize, Default, Clone)]
 pub struct CommitToReleaseDayEntry {
     #[serde(default)]
@@ -243,6 +284,20 @@ pub struct Statistics {
     pub top_authors: Vec<AuthorEntry>,
     #[serde(default)]
     pub release_cycles: ReleaseCycleStats,
+    /// Commit counts keyed by calendar day, for the report's contribution
+    /// heatmap panel. Populated by the data-collection step; if the input
+    /// predates this field, `report::render_report` falls back to grouping
+    /// `CollectedData::commits` itself.
+    #[serde(default)]
+    pub by_day: std::collections::BTreeMap<NaiveDate, u32>,
+    /// Repo-wide commits-per-bucket series for the report's momentum line
+    /// chart, in bucket order.
+    #[serde(default)]
+    pub commit_timeline: Vec<TimelineBucket>,
+    /// Same series per top author, keyed by author name, for the chart's
+    /// optional per-author overlay.
+    #[serde(default)]
+    pub author_timelines: std::collections::HashMap<String, Vec<TimelineBucket>>,
     pub change_flow: Option<ChangeFlowMetrics>,
 }
 
@@ -255,6 +310,60 @@ pub struct CollectedData {
     pub statistics: Option<Statistics>,
 }
 
+/// Date window and branch subset to restrict a `CollectedData` to before
+/// layout, so large repos don't get squashed into one unreadable strip.
+#[derive(Debug, Clone, Default)]
+pub struct LoadOptions {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub branches: Option<Vec<String>>,
+}
+
+/// Parse a `--since`/`--until`-style CLI date (YYYY-MM-DD) as the first
+/// instant of that day, UTC.
+pub fn parse_date_utc_start(s: &str) -> Option<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+}
+
+/// Parse a `--since`/`--until`-style CLI date (YYYY-MM-DD) as the last
+/// instant of that day, UTC, so an `--until` bound is inclusive of the
+/// whole day rather than cutting it off at midnight.
+pub fn parse_date_utc_end(s: &str) -> Option<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(23, 59, 59)?.and_utc())
+}
+
+impl CollectedData {
+    /// Restrict this data to `opts`' date window and branch subset: drops
+    /// commits/merges outside the range or not on a requested branch, and
+    /// prunes `branches` to match. When `opts.since` is unset, defaults to
+    /// one year before the newest commit.
+    pub fn filter(mut self, opts: &LoadOptions) -> CollectedData {
+        let newest = self.commits.iter().map(|c| c.timestamp).max();
+        let since = opts.since.or_else(|| newest.map(|n| n - Duration::days(365)));
+
+        self.commits.retain(|c| {
+            since.map_or(true, |s| c.timestamp >= s)
+                && opts.until.map_or(true, |u| c.timestamp <= u)
+                && opts
+                    .branches
+                    .as_ref()
+                    .map_or(true, |bs| bs.iter().any(|b| b == &c.branch))
+        });
+
+        let kept_branches: std::collections::HashSet<&str> =
+            self.commits.iter().map(|c| c.branch.as_str()).collect();
+        self.merges.retain(|m| {
+            kept_branches.contains(m.from_branch.as_str())
+                && m.timestamp.map_or(true, |t| since.map_or(true, |s| t >= s) && opts.until.map_or(true, |u| t <= u))
+        });
+        self.branches.retain(|b| b.is_default || kept_branches.contains(b.name.as_str()));
+
+        self
+    }
+}
+
 pub fn load_data(path: &Path) -> Result<CollectedData, Box<dyn std::error::Error>> {
     let contents = fs::read_to_string(path)?;
     let data: CollectedData = serde_json::from_str(&contents)?;
@@ -0,0 +1,156 @@
+//! git-describe-style "nearest release" lookup: how many commits a given
+//! commit sits past the closest reachable tag, e.g. `v1.2.3+7`. Mirrors git's
+//! own best-first search over the parent DAG rather than counting
+//! main-branch commits, so it stays correct across merges and branch
+//! topology.
+
+use crate::data::CollectedData;
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Up to 32 tagged ancestors can be tracked as candidates at once — git's own
+/// describe caps it here too, since each candidate claims one bit of a u32
+/// flag set.
+const MAX_CANDIDATES: usize = 32;
+
+/// A commit's distance past the nearest reachable tag.
+pub struct Describe {
+    pub name: String,
+    pub depth: u32,
+}
+
+impl Describe {
+    /// Render as git-describe's own `<tag>` / `<tag>+<depth>` notation.
+    pub fn format(&self) -> String {
+        if self.depth == 0 {
+            self.name.clone()
+        } else {
+            format!("{}+{}", self.name, self.depth)
+        }
+    }
+}
+
+/// A commit queued for the best-first walk, ordered by timestamp (most
+/// recent first) so the search fans out from the target the same way git
+/// walks `--parents` history.
+struct Visit {
+    timestamp: DateTime<Utc>,
+    index: usize,
+}
+
+impl PartialEq for Visit {
+    fn eq(&self, other: &Self) -> bool {
+        self.timestamp == other.timestamp && self.index == other.index
+    }
+}
+impl Eq for Visit {}
+impl PartialOrd for Visit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Visit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp.cmp(&other.timestamp).then_with(|| self.index.cmp(&other.index))
+    }
+}
+
+/// Resolves `Describe`s against a fixed `CollectedData`, indexing SHA ->
+/// commit index once so repeated calls (one per branch tip, one per frame)
+/// don't each pay to rebuild it.
+pub struct Describer<'a> {
+    data: &'a CollectedData,
+    sha_to_idx: HashMap<&'a str, usize>,
+}
+
+impl<'a> Describer<'a> {
+    pub fn new(data: &'a CollectedData) -> Self {
+        let sha_to_idx = data.commits.iter().enumerate().map(|(i, c)| (c.sha.as_str(), i)).collect();
+        Describer { data, sha_to_idx }
+    }
+
+    /// git-describe over the parent DAG, starting from `data.commits[target_index]`.
+    /// Walks parents best-first (largest timestamp first); each tagged
+    /// commit encountered becomes a candidate claiming the next free bit
+    /// (first 32 only), and every visited commit's flag set is the union of
+    /// the candidate bits that can reach it, propagated to its own parents.
+    /// Per candidate, depth is the count of visited commits that *don't*
+    /// carry its bit — i.e. commits on the target's side of that tag. The
+    /// winner is the ancestor candidate with the smallest depth, ties broken
+    /// by the more recently tagged commit.
+    pub fn describe(&self, target_index: usize) -> Option<Describe> {
+        let commits = &self.data.commits;
+
+        let mut flags: HashMap<usize, u32> = HashMap::new();
+        let mut visited: std::collections::HashSet<usize> = std::collections::HashSet::new();
+        let mut candidate_bit: HashMap<usize, u32> = HashMap::new();
+        let mut candidate_order: Vec<usize> = Vec::new();
+        let mut candidate_depth: Vec<u32> = Vec::new();
+
+        let mut heap = BinaryHeap::new();
+        heap.push(Visit { timestamp: commits[target_index].timestamp, index: target_index });
+        flags.insert(target_index, 0);
+
+        while let Some(Visit { index, .. }) = heap.pop() {
+            if !visited.insert(index) {
+                continue;
+            }
+
+            if candidate_order.len() >= MAX_CANDIDATES {
+                let all_flagged = u32::MAX;
+                if flags.get(&index).copied().unwrap_or(0) & all_flagged == all_flagged {
+                    // This, and every remaining queued commit (all no closer
+                    // to the target), already carry every candidate's bit —
+                    // nothing left can change any candidate's depth.
+                    break;
+                }
+            }
+
+            if candidate_order.len() < MAX_CANDIDATES && !candidate_bit.contains_key(&index) {
+                if let Some(tag) = commits[index].tags.first() {
+                    let bit = candidate_order.len() as u32;
+                    candidate_bit.insert(index, bit);
+                    candidate_order.push(index);
+                    // The bit was just born, so every commit visited before
+                    // this one (excluding this one) necessarily lacks it —
+                    // seed the depth with all of them, not just the ones
+                    // that turned out not to be some *other* candidate.
+                    candidate_depth.push((visited.len() - 1) as u32);
+                    let _ = tag;
+                }
+            }
+
+            let my_flags = flags.get(&index).copied().unwrap_or(0)
+                | candidate_bit.get(&index).map_or(0, |&bit| 1 << bit);
+            flags.insert(index, my_flags);
+
+            for (bit_idx, _) in candidate_order.iter().enumerate() {
+                if my_flags & (1 << bit_idx) == 0 {
+                    candidate_depth[bit_idx] += 1;
+                }
+            }
+
+            for parent_sha in &commits[index].parents {
+                let Some(&parent_idx) = self.sha_to_idx.get(parent_sha.as_str()) else { continue };
+                let merged = flags.get(&parent_idx).copied().unwrap_or(0) | my_flags;
+                let changed = flags.get(&parent_idx).copied() != Some(merged);
+                flags.insert(parent_idx, merged);
+                if changed || !visited.contains(&parent_idx) {
+                    heap.push(Visit { timestamp: commits[parent_idx].timestamp, index: parent_idx });
+                }
+            }
+        }
+
+        candidate_order
+            .iter()
+            .zip(candidate_depth.iter())
+            .min_by(|(&i1, d1), (&i2, d2)| {
+                d1.cmp(d2).then_with(|| commits[i2].timestamp.cmp(&commits[i1].timestamp))
+            })
+            .map(|(&idx, &depth)| Describe {
+                name: commits[idx].tags.first().cloned().unwrap_or_default(),
+                depth,
+            })
+    }
+}
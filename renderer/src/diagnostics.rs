@@ -0,0 +1,105 @@
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// One frame's render cost, recorded by whichever output path (`render_segment`,
+/// `render_gif`, `render_to_stdout`) rendered it. Cheap to share across rayon
+/// worker threads: all mutation goes through a single `Mutex<Vec<FrameSample>>`,
+/// the same pattern `timing::Timeline` uses for its spans.
+pub struct FrameSample {
+    pub frame_index: u32,
+    pub render_secs: f64,
+    pub commits_drawn: u32,
+    pub merge_markers_drawn: u32,
+    pub labels_drawn: u32,
+    pub pixels_written: u64,
+}
+
+/// Accumulates `FrameSample`s across the parallel render batches so
+/// `render_video` can print a min/avg/max/p95 summary at the end, and so
+/// `--debug-overlay` can draw a rolling frame-time graph from recent samples.
+pub struct Diagnostics {
+    start: Instant,
+    samples: Mutex<Vec<FrameSample>>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Diagnostics {
+            start: Instant::now(),
+            samples: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn record(&self, sample: FrameSample) {
+        self.samples.lock().unwrap().push(sample);
+    }
+
+    /// Render times (seconds) of up to the last `n` samples recorded so far.
+    /// Parallel batches record out of frame order, so this is a rough recent
+    /// trend for the `--debug-overlay` HUD, not a strictly time-ordered window.
+    pub fn recent_render_secs(&self, n: usize) -> Vec<f64> {
+        let samples = self.samples.lock().unwrap();
+        let start = samples.len().saturating_sub(n);
+        samples[start..].iter().map(|s| s.render_secs).collect()
+    }
+
+    pub fn summary(&self) -> DiagnosticsSummary {
+        let samples = self.samples.lock().unwrap();
+        let mut times: Vec<f64> = samples.iter().map(|s| s.render_secs).collect();
+        times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let count = times.len();
+        let min_frame_secs = times.first().copied().unwrap_or(0.0);
+        let max_frame_secs = times.last().copied().unwrap_or(0.0);
+        let avg_frame_secs = if count > 0 { times.iter().sum::<f64>() / count as f64 } else { 0.0 };
+        let p95_frame_secs = if count > 0 {
+            let idx = ((count as f32 * 0.95).ceil() as usize).saturating_sub(1).min(count - 1);
+            times[idx]
+        } else {
+            0.0
+        };
+        let total_pixels_written: u64 = samples.iter().map(|s| s.pixels_written).sum();
+        let elapsed_secs = self.start.elapsed().as_secs_f64().max(0.000_1);
+
+        DiagnosticsSummary {
+            frame_count: count,
+            min_frame_secs,
+            avg_frame_secs,
+            max_frame_secs,
+            p95_frame_secs,
+            total_pixels_written,
+            effective_fps: count as f64 / elapsed_secs,
+        }
+    }
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct DiagnosticsSummary {
+    pub frame_count: usize,
+    pub min_frame_secs: f64,
+    pub avg_frame_secs: f64,
+    pub max_frame_secs: f64,
+    pub p95_frame_secs: f64,
+    pub total_pixels_written: u64,
+    pub effective_fps: f64,
+}
+
+impl DiagnosticsSummary {
+    pub fn print(&self) {
+        eprintln!(
+            "Render diagnostics: {} frames rendered, {:.1} effective fps, frame time min/avg/p95/max = {:.1}/{:.1}/{:.1}/{:.1} ms, {} total pixels written",
+            self.frame_count,
+            self.effective_fps,
+            self.min_frame_secs * 1000.0,
+            self.avg_frame_secs * 1000.0,
+            self.p95_frame_secs * 1000.0,
+            self.max_frame_secs * 1000.0,
+            self.total_pixels_written,
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Merge-order epoch decomposition: splits the commit DAG (walked in
+//! collected order, which the rest of `layout` already treats as
+//! chronological) into maximal *linear* epochs — runs where each commit has
+//! exactly one parent and one child within the dataset — and minimal
+//! *non-linear* epochs covering a divergence point, its parallel branch
+//! tips, and the merge that reconverges them. `NetworkLayout` uses this to
+//! compress quiet linear stretches and expand fan-out-then-merge bursts
+//! instead of spacing every commit identically, and to reset divergence
+//! bookkeeping at epoch boundaries so vertical branch spread doesn't
+//! accumulate across the whole repo.
+
+use crate::data::CollectedData;
+use std::collections::HashMap;
+
+/// A maximal run of commits (by collected-order index, `[start, end)`) that
+/// is either entirely linear or entirely part of one fan-out/merge region.
+#[derive(Debug, Clone, Copy)]
+pub struct Epoch {
+    pub start: usize,
+    pub end: usize,
+    pub is_linear: bool,
+}
+
+impl Epoch {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+/// Walks commits in collected order tracking how many branch tips a
+/// divergence has opened that haven't yet been reconverged by a merge
+/// (`open_forks`): any commit with more than one parent or child is itself
+/// non-linear, and everything between a fork and its reconverging merge
+/// stays non-linear even where an individual commit's own parent/child
+/// count looks like 1-and-1.
+pub fn compute_epochs(data: &CollectedData) -> Vec<Epoch> {
+    let total = data.commits.len();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let sha_to_idx: HashMap<&str, usize> =
+        data.commits.iter().enumerate().map(|(i, c)| (c.sha.as_str(), i)).collect();
+
+    let mut in_degree = vec![0u32; total];
+    let mut out_degree = vec![0u32; total];
+    for (i, commit) in data.commits.iter().enumerate() {
+        for parent_sha in &commit.parents {
+            if let Some(&parent_idx) = sha_to_idx.get(parent_sha.as_str()) {
+                in_degree[i] += 1;
+                out_degree[parent_idx] += 1;
+            }
+        }
+    }
+
+    let mut epochs = Vec::new();
+    let mut epoch_start = 0usize;
+    let mut epoch_is_linear = true;
+    let mut open_forks: i64 = 0;
+
+    for i in 0..total {
+        let is_pivot = in_degree[i] > 1 || out_degree[i] > 1;
+
+        if out_degree[i] > 1 {
+            open_forks += i64::from(out_degree[i]) - 1;
+        }
+        let commit_is_linear = !is_pivot && open_forks <= 0;
+        if in_degree[i] > 1 {
+            open_forks -= i64::from(in_degree[i]) - 1;
+        }
+
+        if i == 0 {
+            epoch_is_linear = commit_is_linear;
+        } else if commit_is_linear != epoch_is_linear {
+            epochs.push(Epoch { start: epoch_start, end: i, is_linear: epoch_is_linear });
+            epoch_start = i;
+            epoch_is_linear = commit_is_linear;
+        }
+    }
+
+    epochs.push(Epoch { start: epoch_start, end: total, is_linear: epoch_is_linear });
+    epochs
+}
+
+/// Expand an epoch list into a per-commit-index `is_linear` lookup, for
+/// callers that need to classify one commit at a time (x-allocation weight,
+/// divergence-reset boundary checks) rather than walk the spans.
+pub fn linear_flags(epochs: &[Epoch], total: usize) -> Vec<bool> {
+    let mut flags = vec![true; total];
+    for epoch in epochs {
+        for flag in &mut flags[epoch.start..epoch.end] {
+            *flag = epoch.is_linear;
+        }
+    }
+    flags
+}
+
+/// Expand an epoch list into a per-commit-index epoch-ordinal lookup, so
+/// callers can detect "did we just cross into a new epoch" by comparing
+/// consecutive indices' ordinals.
+pub fn epoch_ordinals(epochs: &[Epoch], total: usize) -> Vec<usize> {
+    let mut ordinals = vec![0usize; total];
+    for (ord, epoch) in epochs.iter().enumerate() {
+        for slot in &mut ordinals[epoch.start..epoch.end] {
+            *slot = ord;
+        }
+    }
+    ordinals
+}
@@ -0,0 +1,229 @@
+//! Minimal GIF89a encoder: global color table, LZW-compressed indexed
+//! frames, Netscape loop extension, and per-frame delay — just enough to
+//! write a shareable animated GIF without depending on an external crate.
+
+use crate::palette::Palette;
+use std::io::{self, Write};
+
+pub struct GifEncoder<W: Write> {
+    writer: W,
+    width: u16,
+    height: u16,
+}
+
+impl<W: Write> GifEncoder<W> {
+    /// Write the GIF header, logical screen descriptor, and global color
+    /// table, with an optional Netscape 2.0 looping extension (`loop_count`
+    /// of `None` means play once; `Some(0)` means loop forever).
+    pub fn new(
+        mut writer: W,
+        width: u16,
+        height: u16,
+        palette: &Palette,
+        loop_count: Option<u16>,
+    ) -> io::Result<Self> {
+        writer.write_all(b"GIF89a")?;
+
+        let table_size_bits = color_table_size_bits(palette.colors.len());
+        let table_entries = 1usize << (table_size_bits + 1);
+
+        // Logical screen descriptor: global color table present, color
+        // resolution and table size both derived from the palette.
+        let packed = 0b1000_0000 | (table_size_bits << 4) | table_size_bits;
+        writer.write_all(&width.to_le_bytes())?;
+        writer.write_all(&height.to_le_bytes())?;
+        writer.write_all(&[packed, 0, 0])?;
+
+        for i in 0..table_entries {
+            let c = palette.colors.get(i).copied().unwrap_or([0, 0, 0]);
+            writer.write_all(&c)?;
+        }
+
+        if let Some(loops) = loop_count {
+            writer.write_all(&[0x21, 0xFF, 0x0B])?;
+            writer.write_all(b"NETSCAPE2.0")?;
+            writer.write_all(&[0x03, 0x01])?;
+            writer.write_all(&loops.to_le_bytes())?;
+            writer.write_all(&[0x00])?;
+        }
+
+        Ok(GifEncoder { writer, width, height })
+    }
+
+    /// Write one frame of palette indices (row-major, `width * height` long)
+    /// with a display duration of `delay_cs` hundredths of a second.
+    pub fn write_frame(&mut self, indices: &[u8], delay_cs: u16) -> io::Result<()> {
+        // Graphic control extension: no transparency, dispose-to-nothing.
+        self.writer.write_all(&[0x21, 0xF9, 0x04, 0x00])?;
+        self.writer.write_all(&delay_cs.to_le_bytes())?;
+        self.writer.write_all(&[0x00, 0x00])?;
+
+        // Image descriptor: no local color table, covers the full canvas.
+        self.writer.write_all(&[0x2C])?;
+        self.writer.write_all(&0u16.to_le_bytes())?;
+        self.writer.write_all(&0u16.to_le_bytes())?;
+        self.writer.write_all(&self.width.to_le_bytes())?;
+        self.writer.write_all(&self.height.to_le_bytes())?;
+        self.writer.write_all(&[0x00])?;
+
+        let min_code_size = indices
+            .iter()
+            .copied()
+            .max()
+            .map_or(2, |m| (u32::from(m) + 1).max(4).next_power_of_two().trailing_zeros().max(2) as u8);
+        self.writer.write_all(&[min_code_size])?;
+
+        let compressed = lzw_encode(indices, min_code_size);
+        for chunk in compressed.chunks(255) {
+            self.writer.write_all(&[chunk.len() as u8])?;
+            self.writer.write_all(chunk)?;
+        }
+        self.writer.write_all(&[0x00])?;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+        self.writer.write_all(&[0x3B])
+    }
+}
+
+fn color_table_size_bits(num_colors: usize) -> u8 {
+    let mut bits = 0u8;
+    while (1usize << (bits + 1)) < num_colors && bits < 7 {
+        bits += 1;
+    }
+    bits
+}
+
+/// Standard GIF variable-width LZW compression over palette indices.
+fn lzw_encode(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear_code = 1u32 << min_code_size;
+    let end_code = clear_code + 1;
+    let mut next_code = end_code + 1;
+    let mut code_size = u32::from(min_code_size) + 1;
+
+    let mut dict: std::collections::HashMap<Vec<u8>, u32> = std::collections::HashMap::new();
+    let reset_dict = |dict: &mut std::collections::HashMap<Vec<u8>, u32>| {
+        dict.clear();
+        for i in 0..clear_code {
+            dict.insert(vec![i as u8], i);
+        }
+    };
+    reset_dict(&mut dict);
+
+    let mut bit_writer = BitWriter::new();
+    bit_writer.write_bits(clear_code, code_size);
+
+    let mut current: Vec<u8> = Vec::new();
+    for &byte in indices {
+        let mut candidate = current.clone();
+        candidate.push(byte);
+        if dict.contains_key(&candidate) {
+            current = candidate;
+        } else {
+            let code = dict[&current];
+            bit_writer.write_bits(code, code_size);
+
+            dict.insert(candidate, next_code);
+            next_code += 1;
+            if next_code > (1 << code_size) && code_size < 12 {
+                code_size += 1;
+            }
+            if next_code >= 4096 {
+                bit_writer.write_bits(clear_code, code_size);
+                reset_dict(&mut dict);
+                next_code = end_code + 1;
+                code_size = u32::from(min_code_size) + 1;
+            }
+
+            current = vec![byte];
+        }
+    }
+    if !current.is_empty() {
+        let code = dict[&current];
+        bit_writer.write_bits(code, code_size);
+    }
+    bit_writer.write_bits(end_code, code_size);
+
+    bit_writer.finish()
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    bit_buf: u32,
+    bit_count: u32,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        BitWriter { bytes: Vec::new(), bit_buf: 0, bit_count: 0 }
+    }
+
+    fn write_bits(&mut self, value: u32, num_bits: u32) {
+        self.bit_buf |= value << self.bit_count;
+        self.bit_count += num_bits;
+        while self.bit_count >= 8 {
+            self.bytes.push((self.bit_buf & 0xFF) as u8);
+            self.bit_buf >>= 8;
+            self.bit_count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bit_count > 0 {
+            self.bytes.push((self.bit_buf & 0xFF) as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Quantize an RGBA frame against `palette` with Floyd-Steinberg dithering,
+/// returning one palette index per pixel.
+pub fn dither_frame(rgba: &[u8], width: u32, height: u32, palette: &Palette) -> Vec<u8> {
+    let w = width as usize;
+    let h = height as usize;
+    let mut err = vec![[0.0f32; 3]; w * h];
+    let mut out = vec![0u8; w * h];
+
+    for y in 0..h {
+        for x in 0..w {
+            let i = y * w + x;
+            let idx = i * 4;
+            let mut pixel = [
+                f32::from(rgba[idx]) + err[i][0],
+                f32::from(rgba[idx + 1]) + err[i][1],
+                f32::from(rgba[idx + 2]) + err[i][2],
+            ];
+            for c in &mut pixel {
+                *c = c.clamp(0.0, 255.0);
+            }
+            let quantized = [pixel[0] as u8, pixel[1] as u8, pixel[2] as u8];
+            let pal_idx = palette.nearest_index(quantized);
+            out[i] = pal_idx as u8;
+            let chosen = palette.colors[pal_idx];
+
+            let diff = [
+                pixel[0] - f32::from(chosen[0]),
+                pixel[1] - f32::from(chosen[1]),
+                pixel[2] - f32::from(chosen[2]),
+            ];
+            let mut distribute = |dx: isize, dy: isize, weight: f32| {
+                let nx = x as isize + dx;
+                let ny = y as isize + dy;
+                if nx >= 0 && nx < w as isize && ny >= 0 && ny < h as isize {
+                    let ni = ny as usize * w + nx as usize;
+                    for c in 0..3 {
+                        err[ni][c] += diff[c] * weight;
+                    }
+                }
+            };
+            distribute(1, 0, 7.0 / 16.0);
+            distribute(-1, 1, 3.0 / 16.0);
+            distribute(0, 1, 5.0 / 16.0);
+            distribute(1, 1, 1.0 / 16.0);
+        }
+    }
+
+    out
+}
@@ -0,0 +1,103 @@
+//! Minimal indexed (8-bit palette) PNG encoder: IHDR/PLTE/IDAT/IEND chunks
+//! with a stored (uncompressed) deflate stream and no external crate
+//! dependency. The image data is one palette-index byte per pixel (plus one
+//! filter byte per row), a quarter of raw 32-bit RGBA; it is not compressed,
+//! so the file is not necessarily smaller than a deflate-compressed RGBA PNG.
+
+use std::io;
+use std::path::Path;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let mut a = 1u32;
+    let mut b = 0u32;
+    for &byte in data {
+        a = (a + u32::from(byte)) % MOD_ADLER;
+        b = (b + a) % MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+/// Wrap raw bytes in uncompressed ("stored") deflate blocks. Not actually
+/// compressed, but valid DEFLATE — the only size reduction is the palette
+/// indexing itself (one byte per pixel instead of four).
+fn deflate_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 65535;
+    let mut out = Vec::with_capacity(data.len() + data.len() / MAX_BLOCK.max(1) * 5 + 5);
+    let mut i = 0;
+    loop {
+        let remaining = data.len() - i;
+        let block_len = remaining.min(MAX_BLOCK);
+        let is_final = i + block_len >= data.len();
+        out.push(u8::from(is_final));
+        let len = block_len as u16;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(&data[i..i + block_len]);
+        i += block_len;
+        if is_final {
+            break;
+        }
+    }
+    out
+}
+
+fn write_chunk(out: &mut Vec<u8>, tag: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(tag);
+    out.extend_from_slice(data);
+    let mut crc_input = Vec::with_capacity(4 + data.len());
+    crc_input.extend_from_slice(tag);
+    crc_input.extend_from_slice(data);
+    out.extend_from_slice(&crc32(&crc_input).to_be_bytes());
+}
+
+/// Write an 8-bit indexed PNG: `palette` holds up to 256 RGB entries,
+/// `indices` is one palette index per pixel, row-major, `width * height` long.
+pub fn write(path: &Path, width: u32, height: u32, palette: &[[u8; 3]], indices: &[u8]) -> io::Result<()> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&SIGNATURE);
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    ihdr.extend_from_slice(&[8, 3, 0, 0, 0]); // 8-bit depth, color type 3 (indexed)
+    write_chunk(&mut out, b"IHDR", &ihdr);
+
+    let mut plte = Vec::with_capacity(palette.len() * 3);
+    for color in palette {
+        plte.extend_from_slice(color);
+    }
+    write_chunk(&mut out, b"PLTE", &plte);
+
+    let row_bytes = width as usize;
+    let mut raw = Vec::with_capacity((row_bytes + 1) * height as usize);
+    for row in 0..height as usize {
+        raw.push(0); // filter type 0 (None)
+        raw.extend_from_slice(&indices[row * row_bytes..(row + 1) * row_bytes]);
+    }
+
+    let mut zlib_stream = Vec::with_capacity(raw.len() + 6);
+    zlib_stream.push(0x78);
+    zlib_stream.push(0x01);
+    zlib_stream.extend(deflate_stored(&raw));
+    zlib_stream.extend_from_slice(&adler32(&raw).to_be_bytes());
+    write_chunk(&mut out, b"IDAT", &zlib_stream);
+
+    write_chunk(&mut out, b"IEND", &[]);
+
+    std::fs::write(path, out)
+}
@@ -0,0 +1,148 @@
+//! InfluxDB line-protocol exporter: serializes `Statistics`/`ChangeFlowMetrics`
+//! so a repo's flow metrics can be tracked across successive collection runs
+//! in a time-series dashboard, instead of only rendering a single static
+//! image.
+
+use crate::data::CollectedData;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+fn escape_tag(s: &str) -> String {
+    s.replace(',', "\\,").replace('=', "\\=").replace(' ', "\\ ")
+}
+
+fn datetime_to_ns(dt: DateTime<Utc>) -> i64 {
+    dt.timestamp() * 1_000_000_000 + i64::from(dt.timestamp_subsec_nanos())
+}
+
+/// Convert a `YYYY-MM-DD` date string to nanoseconds since the Unix epoch at
+/// midnight UTC, InfluxDB line protocol's native timestamp precision.
+fn date_to_ns(date_str: &str) -> Option<i64> {
+    let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok()?;
+    Some(datetime_to_ns(date.and_hms_opt(0, 0, 0)?.and_utc()))
+}
+
+/// Serialize `data.statistics`/`data.statistics.change_flow` into InfluxDB
+/// line protocol: one measurement per time-series vector (`commit_velocity`,
+/// `release_latency`, `merge_latency`, `branch_lifespan`, `drought`), plus
+/// one-shot gauge points for the scalar summary fields, stamped at export
+/// time. Returns an empty string if there's no statistics to export.
+pub fn to_line_protocol(data: &CollectedData) -> String {
+    let mut out = String::new();
+    let Some(stats) = &data.statistics else { return out };
+
+    let repo = escape_tag(&data.metadata.repo);
+    let now_ns = datetime_to_ns(Utc::now());
+
+    out.push_str(&format!(
+        "repo_stats,repo={} total_commits={}i,unique_authors={}i,commits_per_week={},date_span_days={}i {}\n",
+        repo, stats.total_commits, stats.unique_authors, stats.commits_per_week, stats.date_span_days, now_ns
+    ));
+
+    let Some(cf) = &stats.change_flow else { return out };
+
+    for dv in &cf.daily_velocity {
+        if let Some(ts) = date_to_ns(&dv.date) {
+            out.push_str(&format!(
+                "commit_velocity,repo={},category={} count={}i {}\n",
+                repo, escape_tag(&dv.dominant_category), dv.count, ts
+            ));
+        }
+    }
+
+    for e in &cf.commit_to_release_days {
+        if let Some(ts) = date_to_ns(&e.date) {
+            out.push_str(&format!(
+                "release_latency,repo={} avg_days_to_release={},unreleased_count={}i {}\n",
+                repo, e.avg_days_to_release, e.unreleased_count, ts
+            ));
+        }
+    }
+
+    for e in &cf.commit_merge_latency {
+        if let Some(ts) = date_to_ns(&e.commit_date) {
+            let mut fields = format!("lines_changed={}i", e.lines_changed);
+            if let Some(days) = e.days_to_merge {
+                fields.push_str(&format!(",days_to_merge={days}"));
+            }
+            out.push_str(&format!(
+                "merge_latency,repo={},category={} {} {}\n",
+                repo, escape_tag(&e.category), fields, ts
+            ));
+        }
+    }
+
+    for bl in &cf.branch_lifespans {
+        if let Some(ts) = date_to_ns(&bl.last_commit) {
+            out.push_str(&format!(
+                "branch_lifespan,repo={},branch={} lifespan_days={},merged={} {}\n",
+                repo, escape_tag(&bl.branch), bl.lifespan_days, bl.merged, ts
+            ));
+        }
+    }
+
+    for d in &cf.drought_periods {
+        if let Some(ts) = date_to_ns(&d.start_date) {
+            out.push_str(&format!(
+                "drought,repo={} duration_days={}i {}\n",
+                repo, d.duration_days, ts
+            ));
+        }
+    }
+
+    out.push_str(&format!(
+        "change_flow_summary,repo={} release_median_latency={},release_p90_latency={},release_pct_within_7d={},\
+branch_median_lifespan={},branch_unmerged_count={}i,branch_longest_days={},drought_count={}i,\
+longest_drought_days={}i,total_drought_days={}i,merge_median_latency={},merge_pct_within_7d={},\
+merge_pct_within_30d={},release_interval_mean={},release_interval_median={},release_interval_cv={},\
+release_interval_longest_gap={} {}\n",
+        repo,
+        cf.release_median_latency, cf.release_p90_latency, cf.release_pct_within_7d,
+        cf.branch_median_lifespan, cf.branch_unmerged_count, cf.branch_longest_days,
+        cf.drought_count, cf.longest_drought_days, cf.total_drought_days,
+        cf.merge_median_latency, cf.merge_pct_within_7d, cf.merge_pct_within_30d,
+        cf.release_interval_mean, cf.release_interval_median, cf.release_interval_cv, cf.release_interval_longest_gap,
+        now_ns
+    ));
+
+    out
+}
+
+/// Parse an `http://host[:port]/path` URL into its connection parts. Plain
+/// HTTP only (no TLS) — same scope-limited, no-new-dependency tradeoff as
+/// the hand-rolled GIF/PNG encoders elsewhere in this crate.
+fn parse_http_url(url: &str) -> std::io::Result<(String, u16, String)> {
+    let invalid = || std::io::Error::new(std::io::ErrorKind::InvalidInput, "invalid InfluxDB URL (expected http://host[:port]/path)");
+    let rest = url.strip_prefix("http://").ok_or_else(invalid)?;
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((h, p)) => (h.to_string(), p.parse::<u16>().map_err(|_| invalid())?),
+        None => (authority.to_string(), 80),
+    };
+    Ok((host, port, path.to_string()))
+}
+
+/// POST `body` as InfluxDB line protocol to `http://host[:port]/write?db=...`,
+/// over a raw `TcpStream` HTTP/1.1 request (no external HTTP client crate).
+pub fn write_to_influxdb(url: &str, body: &str) -> std::io::Result<()> {
+    let (host, port, path) = parse_http_url(url)?;
+    let mut stream = TcpStream::connect((host.as_str(), port))?;
+
+    let request = format!(
+        "POST {path} HTTP/1.1\r\nHost: {host}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    let status_line = response.lines().next().unwrap_or("");
+    if !status_line.contains("204") && !status_line.contains("200") {
+        return Err(std::io::Error::other(format!("InfluxDB write failed: {status_line}")));
+    }
+    Ok(())
+}
@@ -1,6 +1,134 @@
 use crate::data::{CollectedData, Commit};
+use crate::epoch;
+use crate::lineage;
 use chrono::Datelike;
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// How to map a commit's position in `data.commits` to its horizontal rank
+/// (the argument to `commit_to_x`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitOrder {
+    /// Today's behavior: rank equals index in `data.commits`, trusting the
+    /// collector already handed us a sane order.
+    AsCollected,
+    /// Strict chronological order, but a commit is still deferred until all
+    /// its parents have been placed — matches git's `--date-order`.
+    DateOrder,
+    /// A real topological sort over `Commit::parents`, so a commit's rank is
+    /// always greater than every ancestor's — matches git's `--topo-order`.
+    TopoOrder,
+}
+
+impl CommitOrder {
+    pub fn from_str_or_default(s: &str) -> CommitOrder {
+        match s {
+            "date" => CommitOrder::DateOrder,
+            "topo" => CommitOrder::TopoOrder,
+            _ => CommitOrder::AsCollected,
+        }
+    }
+}
+
+/// A commit ready to be placed (all parents already placed), ordered by
+/// timestamp for the ready-set heaps in `commit_ranks`.
+#[derive(Debug, PartialEq, Eq)]
+struct ReadyCommit {
+    timestamp: chrono::DateTime<chrono::Utc>,
+    index: usize,
+}
+
+impl Ord for ReadyCommit {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.timestamp.cmp(&other.timestamp).then_with(|| self.index.cmp(&other.index))
+    }
+}
+
+impl PartialOrd for ReadyCommit {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Map each index in `data.commits` to its rank under `order` — the value
+/// `commit_to_x` should use in place of the raw collector index. Builds an
+/// adjacency map from `Commit::parents` and runs Kahn's algorithm with a
+/// binary heap of "ready" commits (all parents already ranked): `TopoOrder`
+/// pops the ready commit with the *largest* timestamp each step, which
+/// naturally keeps following one branch's thread (emitting a commit unlocks
+/// its child, almost always the most recent timestamp around) without any
+/// explicit branch bookkeeping; `DateOrder` pops the *smallest* timestamp
+/// instead, for a strictly chronological order that's still DAG-safe. This
+/// mirrors git's own `--topo-order`/`--date-order` distinction.
+fn commit_ranks(data: &CollectedData, order: CommitOrder) -> Vec<usize> {
+    let total = data.commits.len();
+    if order == CommitOrder::AsCollected {
+        return (0..total).collect();
+    }
+
+    let sha_to_idx: HashMap<&str, usize> =
+        data.commits.iter().enumerate().map(|(i, c)| (c.sha.as_str(), i)).collect();
+
+    let mut pending_parents = vec![0u32; total];
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); total];
+    for (i, commit) in data.commits.iter().enumerate() {
+        for parent_sha in &commit.parents {
+            if let Some(&parent_idx) = sha_to_idx.get(parent_sha.as_str()) {
+                pending_parents[i] += 1;
+                children[parent_idx].push(i);
+            }
+        }
+    }
+
+    let mut rank = vec![0usize; total];
+    let mut next_rank = 0usize;
+    let initial_ready = (0..total).filter(|&i| pending_parents[i] == 0);
+
+    if order == CommitOrder::TopoOrder {
+        let mut ready: BinaryHeap<ReadyCommit> = initial_ready
+            .map(|i| ReadyCommit { timestamp: data.commits[i].timestamp, index: i })
+            .collect();
+        while let Some(ReadyCommit { index, .. }) = ready.pop() {
+            rank[index] = next_rank;
+            next_rank += 1;
+            for &child in &children[index] {
+                pending_parents[child] -= 1;
+                if pending_parents[child] == 0 {
+                    ready.push(ReadyCommit { timestamp: data.commits[child].timestamp, index: child });
+                }
+            }
+        }
+    } else {
+        let mut ready: BinaryHeap<Reverse<ReadyCommit>> = initial_ready
+            .map(|i| Reverse(ReadyCommit { timestamp: data.commits[i].timestamp, index: i }))
+            .collect();
+        while let Some(Reverse(ReadyCommit { index, .. })) = ready.pop() {
+            rank[index] = next_rank;
+            next_rank += 1;
+            for &child in &children[index] {
+                pending_parents[child] -= 1;
+                if pending_parents[child] == 0 {
+                    ready.push(Reverse(ReadyCommit { timestamp: data.commits[child].timestamp, index: child }));
+                }
+            }
+        }
+    }
+
+    // Only a cycle leaves commits permanently pending (parents outside this,
+    // possibly `--since`-filtered, dataset are never counted above); append
+    // them in collected order so they still get a rank rather than being
+    // silently dropped from the layout.
+    for i in 0..total {
+        if pending_parents[i] != 0 {
+            rank[i] = next_rank;
+            next_rank += 1;
+        }
+    }
+
+    rank
+}
+
+pub use crate::lineage::FileFlow;
 
 pub struct PositionedCommit<'a> {
     pub commit: &'a Commit,
@@ -12,6 +140,11 @@ pub struct PositionedCommit<'a> {
     pub is_default_branch: bool,
     pub branch_has_conflicts: bool,
     pub branch_is_stale: bool,
+    /// Churn accumulated across this file's whole rename history (see
+    /// `lineage::track_lineage`), not just this commit's own delta — a
+    /// renamed file's rect grows with its full lineage rather than
+    /// resetting at the rename.
+    pub lineage_churn: u64,
 }
 
 pub struct PositionedMerge {
@@ -110,17 +243,55 @@ impl NetworkLayout {
         }
     }
 
-    fn commit_to_x(&self, index: usize, total: usize) -> f32 {
+    /// Allocate x across the rank axis so long linear epochs (see `epoch`)
+    /// compress and non-linear fan-out/merge epochs expand, instead of every
+    /// commit getting identical spacing. `is_linear` is indexed by
+    /// collected-order commit index — the weight a commit carries is an
+    /// intrinsic property of its place in the DAG — while `ranks` gives the
+    /// left-to-right order to lay those weighted slices out in, so this
+    /// still respects whichever `CommitOrder` the caller picked.
+    fn epoch_x_positions(&self, ranks: &[usize], is_linear: &[bool], total: usize) -> Vec<f32> {
+        const LINEAR_WEIGHT: f32 = 0.35;
+        const DIVERGENT_WEIGHT: f32 = 2.5;
+
         let usable = self.width as f32 - self.margin_left - self.margin_right;
-        if total <= 1 {
-            return self.margin_left + usable / 2.0;
+        if total == 0 {
+            return Vec::new();
+        }
+        if total == 1 {
+            return vec![self.margin_left + usable / 2.0];
+        }
+
+        let mut by_rank = vec![0usize; total];
+        for (commit_idx, &rank) in ranks.iter().enumerate() {
+            by_rank[rank] = commit_idx;
+        }
+
+        let weights: Vec<f32> = by_rank
+            .iter()
+            .map(|&idx| if is_linear[idx] { LINEAR_WEIGHT } else { DIVERGENT_WEIGHT })
+            .collect();
+        let total_weight: f32 = weights.iter().sum();
+
+        let mut positions = vec![0.0f32; total];
+        let mut cum = 0.0f32;
+        for (rank, &w) in weights.iter().enumerate() {
+            let slice_start = cum;
+            cum += w;
+            let mid = (slice_start + cum) / 2.0;
+            positions[rank] = self.margin_left + (mid / total_weight) * usable;
         }
-        self.margin_left + (index as f32 / (total - 1) as f32) * usable
+
+        positions
     }
 
-    fn commit_rect(commit: &Commit) -> (f32, f32) {
+    /// `lineage_churn` is this commit's own `insertions+deletions` unless a
+    /// rename carried forward prior history (see `lineage::track_lineage`),
+    /// in which case it's at least that large — so a renamed file's rect
+    /// grows with its whole history instead of resetting at the rename.
+    fn commit_rect(commit: &Commit, lineage_churn: u64) -> (f32, f32) {
         let files = commit.files_changed.max(1) as f32;
-        let lines = (commit.insertions + commit.deletions).max(1) as f32;
+        let lines = (lineage_churn.max(1)) as f32;
 
         let w = (files.ln() / 10.0_f32.ln()) * (MAX_RECT_W - MIN_RECT_W) + MIN_RECT_W;
         let h = (lines.ln() / 10.0_f32.ln()) * (MAX_RECT_H - MIN_RECT_H) + MIN_RECT_H;
@@ -144,11 +315,22 @@ impl NetworkLayout {
 
     /// Walk commits chronologically, assign slots on first appearance,
     /// compute dynamic Y per commit based on cumulative branch divergence.
+    /// `order` controls each commit's horizontal rank (see `CommitOrder`);
+    /// it only changes `x`, not Y/slot/divergence, which stay driven by
+    /// collected order and timestamps as before.
     pub fn position_commits_dynamic<'a>(
         &self,
         data: &'a CollectedData,
-    ) -> (Vec<PositionedCommit<'a>>, Vec<BranchVisualInfo>) {
+        order: CommitOrder,
+    ) -> (Vec<PositionedCommit<'a>>, Vec<BranchVisualInfo>, Vec<FileFlow>) {
         let total = data.commits.len();
+        let ranks = commit_ranks(data, order);
+        let lineage = lineage::track_lineage(data);
+
+        let epochs = epoch::compute_epochs(data);
+        let epoch_is_linear = epoch::linear_flags(&epochs, total);
+        let epoch_ordinal = epoch::epoch_ordinals(&epochs, total);
+        let rank_to_x = self.epoch_x_positions(&ranks, &epoch_is_linear, total);
         let mut branch_states: HashMap<String, BranchDivergenceState> = HashMap::new();
         let mut result = Vec::with_capacity(total);
 
@@ -262,11 +444,63 @@ impl NetworkLayout {
             }
         }
 
-        let branch_slot_map: HashMap<&str, usize> = dfs_order
+        // Pack branches into color lanes via interval-graph coloring instead
+        // of handing every branch its own ever-incrementing slot: each
+        // branch's [first_commit, last_commit] timestamp span greedily
+        // claims the lowest-index lane whose current occupant's span has
+        // already ended, so short-lived branches that never coexist share a
+        // lane (and hence a color cycle) instead of each consuming a
+        // permanent one. The default branch is pinned to lane 0. Vertical
+        // position (`base_y`, below) still comes from the parent hierarchy —
+        // lanes here only drive color reuse via `branch_color(slot, ..)`.
+        let mut branch_span: HashMap<&str, (chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)> =
+            HashMap::new();
+        for c in &data.commits {
+            branch_span
+                .entry(c.branch.as_str())
+                .and_modify(|(start, end)| {
+                    if c.timestamp < *start {
+                        *start = c.timestamp;
+                    }
+                    if c.timestamp > *end {
+                        *end = c.timestamp;
+                    }
+                })
+                .or_insert((c.timestamp, c.timestamp));
+        }
+
+        let mut lane_ends: Vec<chrono::DateTime<chrono::Utc>> =
+            vec![chrono::DateTime::<chrono::Utc>::default()];
+        let mut branch_slot_map: HashMap<&str, usize> = HashMap::new();
+        branch_slot_map.insert(self.default_branch.as_str(), 0);
+
+        let mut packing_order: Vec<&str> = dfs_order
             .iter()
-            .enumerate()
-            .map(|(i, name)| (*name, i))
+            .copied()
+            .filter(|name| branch_span.contains_key(name))
             .collect();
+        packing_order.sort_by_key(|name| branch_span[name].0);
+
+        for name in packing_order {
+            let (start, end) = branch_span[name];
+            let free_lane = lane_ends
+                .iter()
+                .enumerate()
+                .skip(1)
+                .find(|(_, last_end)| **last_end < start)
+                .map(|(i, _)| i);
+            let lane = match free_lane {
+                Some(i) => {
+                    lane_ends[i] = end;
+                    i
+                }
+                None => {
+                    lane_ends.push(end);
+                    lane_ends.len() - 1
+                }
+            };
+            branch_slot_map.insert(name, lane);
+        }
 
         // Compute hierarchical base_y for each branch (DFS order guarantees parent computed first)
         let mut branch_base_y: HashMap<&str, f32> = HashMap::new();
@@ -299,9 +533,21 @@ impl NetworkLayout {
         }
 
         for (i, commit) in data.commits.iter().enumerate() {
-            let x = self.commit_to_x(i, total);
+            let x = rank_to_x[ranks[i]];
             let is_default = commit.branch == self.default_branch;
 
+            // Cap vertical spread within each fan-out region rather than
+            // letting it accumulate across the whole repo's history: once
+            // we cross into a new epoch, every branch's divergence budget
+            // starts fresh.
+            if i > 0 && epoch_ordinal[i] != epoch_ordinal[i - 1] {
+                for state in branch_states.values_mut() {
+                    state.cum_commits = 0;
+                    state.cum_lines = 0;
+                    state.cum_files = 0;
+                }
+            }
+
             let (y, slot, has_conflicts, is_stale) = if is_default {
                 (self.main_y, 0, false, false)
             } else {
@@ -340,7 +586,8 @@ impl NetworkLayout {
                 (y, state.slot, state.has_conflicts, state.is_stale)
             };
 
-            let (rect_w, rect_h) = Self::commit_rect(commit);
+            let lineage_churn = lineage.accumulated_churn[i];
+            let (rect_w, rect_h) = Self::commit_rect(commit, lineage_churn);
 
             result.push(PositionedCommit {
                 commit,
@@ -352,6 +599,7 @@ impl NetworkLayout {
                 is_default_branch: is_default,
                 branch_has_conflicts: has_conflicts,
                 branch_is_stale: is_stale,
+                lineage_churn,
             });
         }
 
@@ -377,10 +625,18 @@ impl NetworkLayout {
             })
             .collect();
 
-        (result, branch_infos)
+        (result, branch_infos, lineage.flows)
     }
 
     /// Look up merge positions from positioned commits (not fixed lanes).
+    ///
+    /// The source endpoint prefers `m.merged_sha` (the actual tip commit
+    /// being merged in) when the collector recorded it, since that's exact;
+    /// otherwise it falls back to the last commit on `from_branch` at or
+    /// before `m.timestamp` (or before the merge commit's position, if no
+    /// timestamp is available either) — a lane/time-aware refinement of a
+    /// plain backward scan, so the arc anchors on the commit that was
+    /// actually merged rather than whatever happens to be nearest by index.
     #[allow(clippy::unused_self)]
     pub fn position_merges_dynamic(
         &self,
@@ -394,19 +650,33 @@ impl NetworkLayout {
             .map(|(i, pc)| (pc.commit.sha.as_str(), i))
             .collect();
 
-        // For each merge, find the last commit on from_branch before the merge commit,
-        // and the merge commit itself.
         data.merges
             .iter()
             .filter_map(|m| {
                 let merge_idx = sha_to_idx.get(m.sha.as_str())?;
                 let merge_pc = &positioned_commits[*merge_idx];
 
-                // Find the last commit on from_branch that appears before this merge
-                let from_pc = positioned_commits[..*merge_idx]
-                    .iter()
-                    .rev()
-                    .find(|pc| pc.commit.branch == m.from_branch)?;
+                let from_pc = if let Some(merged_idx) =
+                    m.merged_sha.as_deref().and_then(|sha| sha_to_idx.get(sha))
+                {
+                    &positioned_commits[*merged_idx]
+                } else if let Some(ts) = m.timestamp {
+                    positioned_commits[..*merge_idx]
+                        .iter()
+                        .rev()
+                        .find(|pc| pc.commit.branch == m.from_branch && pc.commit.timestamp <= ts)
+                        .or_else(|| {
+                            positioned_commits[..*merge_idx]
+                                .iter()
+                                .rev()
+                                .find(|pc| pc.commit.branch == m.from_branch)
+                        })?
+                } else {
+                    positioned_commits[..*merge_idx]
+                        .iter()
+                        .rev()
+                        .find(|pc| pc.commit.branch == m.from_branch)?
+                };
 
                 Some(PositionedMerge {
                     from_x: from_pc.x,
@@ -444,6 +714,46 @@ impl NetworkLayout {
             .collect()
     }
 
+    /// Label each branch's most recent (tip) commit with how far past the
+    /// nearest reachable tag it sits, git-describe style (e.g. "v1.2.3+7"),
+    /// using the real parent DAG rather than literal tag markers. Skipped
+    /// for tips that are themselves tagged and for tips with no reachable
+    /// tag at all. `position_tags` marks tagged tips on the default branch
+    /// only, so a tagged tip on any other branch gets no label from either.
+    #[allow(clippy::unused_self)]
+    pub fn position_release_labels(
+        &self,
+        data: &CollectedData,
+        positioned_commits: &[PositionedCommit],
+    ) -> Vec<PositionedTag> {
+        let mut tip_index: HashMap<&str, usize> = HashMap::new();
+        for (i, pc) in positioned_commits.iter().enumerate() {
+            tip_index.insert(pc.commit.branch.as_str(), i);
+        }
+
+        let describer = crate::describe::Describer::new(data);
+
+        tip_index
+            .values()
+            .filter_map(|&i| {
+                let pc = &positioned_commits[i];
+                if !pc.commit.tags.is_empty() {
+                    return None;
+                }
+                let d = describer.describe(i)?;
+                if d.depth == 0 {
+                    return None;
+                }
+                Some(PositionedTag {
+                    x: pc.x,
+                    main_y: pc.y,
+                    label_y: pc.y - 20.0,
+                    tag_name: d.format(),
+                })
+            })
+            .collect()
+    }
+
     /// Compute branch labels: the first commit position for each branch (including default).
     #[allow(clippy::unused_self)]
     pub fn compute_branch_labels(&self, positioned: &[PositionedCommit<'_>]) -> Vec<BranchLabel> {
@@ -475,25 +785,23 @@ impl NetworkLayout {
         labels
     }
 
-    pub fn compute_date_ticks(&self, data: &CollectedData) -> Vec<DateTick> {
-        if data.commits.is_empty() {
-            return Vec::new();
-        }
-
-        let total = data.commits.len();
+    /// Ticks are placed at each positioned commit's actual `x`, so they line
+    /// up with the epoch-compressed/expanded spacing `position_commits_dynamic`
+    /// computed rather than a uniform fallback of their own.
+    #[allow(clippy::unused_self)]
+    pub fn compute_date_ticks(&self, positioned: &[PositionedCommit<'_>]) -> Vec<DateTick> {
         let mut ticks = Vec::new();
         let mut last_month: Option<(i32, u32)> = None;
 
-        for (i, commit) in data.commits.iter().enumerate() {
-            let year = commit.timestamp.year();
-            let month = commit.timestamp.month();
+        for pc in positioned {
+            let year = pc.commit.timestamp.year();
+            let month = pc.commit.timestamp.month();
             let key = (year, month);
 
             if last_month != Some(key) {
                 last_month = Some(key);
-                let x = self.commit_to_x(i, total);
                 let label = format!("{year}/{month:02}");
-                ticks.push(DateTick { x, label });
+                ticks.push(DateTick { x: pc.x, label });
             }
         }
 
@@ -0,0 +1,78 @@
+//! Rename-aware file lineage: `NetworkLayout::commit_rect` used to scale a
+//! commit's rectangle by its own raw `files_changed`/`insertions+deletions`,
+//! treating a renamed file as brand new and losing its accumulated churn
+//! history. This walks commits in collected order (already topological —
+//! see `layout::CommitOrder`) maintaining a Mercurial-style copy map keyed
+//! by current path: when a commit records a rename `A -> B`, `A`'s
+//! accumulated churn folds into `B`, and the mapping is stamped with the
+//! commit's position so a later rename of `B` resolves through to the same
+//! lineage rather than starting over.
+
+use crate::data::CollectedData;
+use std::collections::HashMap;
+
+/// A rename edge the renderer can draw as a faint thread from where a file
+/// used to live to where it lives now.
+pub struct FileFlow {
+    pub source_position: usize,
+    pub dest_position: usize,
+    pub from_path: String,
+    pub to_path: String,
+}
+
+pub struct Lineage {
+    /// Accumulated churn (insertions+deletions) carried by whichever
+    /// lineage this commit's changes belong to, indexed by commit position.
+    /// For a commit with no renames this is just its own churn; for a
+    /// commit that renames `A -> B`, it's `A`'s prior accumulated churn plus
+    /// this commit's own.
+    pub accumulated_churn: Vec<u64>,
+    pub flows: Vec<FileFlow>,
+}
+
+pub fn track_lineage(data: &CollectedData) -> Lineage {
+    let num_commits = data.commits.len();
+    let mut accumulated_churn = vec![0u64; num_commits];
+    let mut flows = Vec::new();
+
+    // Current path -> (accumulated churn, position last touched), i.e. the
+    // copy map's value side folded together with the running churn total.
+    let mut lineage_churn: HashMap<String, u64> = HashMap::new();
+    let mut lineage_position: HashMap<String, usize> = HashMap::new();
+
+    for (i, commit) in data.commits.iter().enumerate() {
+        let commit_churn = u64::from(commit.insertions + commit.deletions);
+
+        if commit.renames.is_empty() {
+            accumulated_churn[i] = commit_churn;
+            continue;
+        }
+
+        let mut total = commit_churn;
+        for (rename_idx, rename) in commit.renames.iter().enumerate() {
+            let carried = lineage_churn.remove(&rename.from).unwrap_or(0);
+            let source_position = lineage_position.remove(&rename.from);
+            total += carried;
+
+            // This commit's own churn belongs to the commit once, not to
+            // every renamed path it touches — fold it into just the first
+            // rename's forward-carried total so a commit renaming N files
+            // doesn't count its own churn N times over.
+            let own_share = if rename_idx == 0 { commit_churn } else { 0 };
+            lineage_churn.insert(rename.to.clone(), carried + own_share);
+            lineage_position.insert(rename.to.clone(), i);
+
+            if let Some(source_position) = source_position {
+                flows.push(FileFlow {
+                    source_position,
+                    dest_position: i,
+                    from_path: rename.from.clone(),
+                    to_path: rename.to.clone(),
+                });
+            }
+        }
+        accumulated_churn[i] = total;
+    }
+
+    Lineage { accumulated_churn, flows }
+}
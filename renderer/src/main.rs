@@ -1,20 +1,38 @@
+mod annotations;
+mod calendar_heatmap;
+mod commit_graph;
 mod config;
 mod data;
+mod describe;
+mod diagnostics;
+mod epoch;
+mod gif;
+mod indexed_png;
+mod influx_export;
 mod layout;
+mod lineage;
+mod palette;
 mod render;
 mod report;
+mod sixel;
 mod stats;
 mod text;
+mod timing;
+mod tui;
 
+mod canvas;
 mod change_flow_charts;
+mod terminal;
 
 use clap::Parser;
 use config::RenderConfig;
 use std::time::Instant;
+use timing::Timeline;
 
 fn main() {
     let total_start = Instant::now();
     let config = RenderConfig::parse();
+    let timeline = Timeline::new(total_start);
 
     let num_threads = rayon::current_num_threads();
     eprintln!("Parallelization: {} threads available (rayon auto-detected)", num_threads);
@@ -29,6 +47,13 @@ fn main() {
             std::process::exit(1);
         }
     };
+    let load_options = data::LoadOptions {
+        since: config.since.as_deref().and_then(data::parse_date_utc_start),
+        until: config.until.as_deref().and_then(data::parse_date_utc_end),
+        branches: if config.branches.is_empty() { None } else { Some(config.branches.clone()) },
+    };
+    let data = data.filter(&load_options);
+    timeline.record("Load data", "load_data", phase_start, phase_start.elapsed(), None);
 
     eprintln!(
         "Loaded {} commits, {} branches, {} merges [{:.2}s]",
@@ -38,28 +63,160 @@ fn main() {
         phase_start.elapsed().as_secs_f64()
     );
 
-    // Phase 2: Statistics report
+    // Phase 2: Video rendering (parallel frame generation) — runs before the
+    // statistics report so per-frame render complexity metrics are available
+    // to it.
+    let phase_start = Instant::now();
+    let (frame_metrics, cache_stats) = match render::render_video(&data, &config, Some(&timeline)) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Error rendering video: {}", e);
+            std::process::exit(1);
+        }
+    };
+    timeline.record("Video rendering", "render_video", phase_start, phase_start.elapsed(), None);
+    if config.cache_dir.is_some() {
+        eprintln!(
+            "Video rendering complete [{:.2}s] (cache: {} hits, {} misses)",
+            phase_start.elapsed().as_secs_f64(),
+            cache_stats.hits,
+            cache_stats.misses
+        );
+    } else {
+        eprintln!("Video rendering complete [{:.2}s]", phase_start.elapsed().as_secs_f64());
+    }
+
+    if let Some(ref stats_json_path) = config.stats_json {
+        match serde_json::to_vec_pretty(&frame_metrics) {
+            Ok(bytes) => {
+                if let Err(e) = std::fs::write(stats_json_path, bytes) {
+                    eprintln!("Error writing stats JSON: {}", e);
+                    std::process::exit(1);
+                }
+                eprintln!("Per-frame render stats written to {:?}", stats_json_path);
+            }
+            Err(e) => {
+                eprintln!("Error serializing stats JSON: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // Phase 3: Statistics report
     if let Some(ref report_path) = config.report_output {
         let phase_start = Instant::now();
         eprintln!("Generating statistics report...");
-        if let Err(e) = report::render_report(&data, report_path) {
+        let render_complexity = stats::summarize_render_complexity(&frame_metrics);
+        let report_theme = report::Theme::from_str_or_default(&config.report_theme);
+        if let Err(e) = report::render_report(&data, report_path, render_complexity.as_ref(), report_theme) {
             eprintln!("Error rendering report: {}", e);
             std::process::exit(1);
         }
+        timeline.record("Statistics report", "render_report", phase_start, phase_start.elapsed(), None);
         eprintln!("Report written to {:?} [{:.2}s]", report_path, phase_start.elapsed().as_secs_f64());
     }
 
-    // Phase 3: Change flow visualizations (parallel chart rendering)
+    // Phase 3a2: Multi-repository comparison report
+    if let Some(ref comparison_path) = config.comparison_report_output {
+        let phase_start = Instant::now();
+        eprintln!("Generating multi-repository comparison report...");
+        let mut others = Vec::new();
+        for path in &config.repos {
+            match data::load_data(path) {
+                Ok(other) => others.push(other),
+                Err(e) => eprintln!("Warning: failed to load {:?}: {}", path, e),
+            }
+        }
+        let mut datasets: Vec<&data::CollectedData> = vec![&data];
+        datasets.extend(others.iter());
+        let comparison_theme = report::Theme::from_str_or_default(&config.report_theme);
+        if let Err(e) = report::render_comparison_report(&datasets, comparison_path, comparison_theme) {
+            eprintln!("Error rendering comparison report: {}", e);
+            std::process::exit(1);
+        }
+        timeline.record("Comparison report", "render_comparison_report", phase_start, phase_start.elapsed(), None);
+        eprintln!("Comparison report written to {:?} [{:.2}s]", comparison_path, phase_start.elapsed().as_secs_f64());
+    }
+
+    // Phase 3b: Calendar heatmap
+    if let Some(ref heatmap_path) = config.calendar_heatmap_output {
+        let phase_start = Instant::now();
+        eprintln!("Generating calendar heatmap...");
+        let colors = calendar_heatmap::HeatmapColors::from_str_or_default(&config.calendar_heatmap_colors);
+        if let Err(e) = calendar_heatmap::render_calendar_heatmap(&data, heatmap_path, colors) {
+            eprintln!("Error rendering calendar heatmap: {}", e);
+            std::process::exit(1);
+        }
+        timeline.record("Calendar heatmap", "render_calendar_heatmap", phase_start, phase_start.elapsed(), None);
+        eprintln!("Calendar heatmap written to {:?} [{:.2}s]", heatmap_path, phase_start.elapsed().as_secs_f64());
+    }
+
+    // Phase 3c: InfluxDB line-protocol export
+    if config.influx_output.is_some() || config.influx_url.is_some() {
+        let phase_start = Instant::now();
+        eprintln!("Exporting InfluxDB line protocol...");
+        let line_protocol = influx_export::to_line_protocol(&data);
+        if let Some(ref path) = config.influx_output {
+            if let Err(e) = std::fs::write(path, &line_protocol) {
+                eprintln!("Error writing InfluxDB line protocol: {}", e);
+                std::process::exit(1);
+            }
+            eprintln!("InfluxDB line protocol written to {:?}", path);
+        }
+        if let Some(ref url) = config.influx_url {
+            if let Err(e) = influx_export::write_to_influxdb(url, &line_protocol) {
+                eprintln!("Error posting to InfluxDB: {}", e);
+                std::process::exit(1);
+            }
+            eprintln!("InfluxDB metrics posted to {}", url);
+        }
+        timeline.record("InfluxDB export", "to_line_protocol", phase_start, phase_start.elapsed(), None);
+    }
+
+    // Phase 4: Change flow visualizations (parallel chart rendering)
     if let Some(ref cf_dir) = config.change_flow_dir {
         let phase_start = Instant::now();
         eprintln!("Generating change flow visualizations ({} threads)...", num_threads);
+        let window = change_flow_charts::DateWindow::from_args(config.since.as_deref(), config.until.as_deref());
+        let scheme = change_flow_charts::ColorScheme::from_str_or_default(&config.color_scheme);
         if let Some(ref stats) = data.statistics {
             if let Some(ref cf) = stats.change_flow {
-                if let Err(e) = change_flow_charts::render_all(cf, cf_dir) {
+                let multi = if config.repos.is_empty() {
+                    None
+                } else {
+                    let mut repos = vec![(data.metadata.repo.clone(), cf.clone())];
+                    for path in &config.repos {
+                        match data::load_data(path) {
+                            Ok(other) => {
+                                if let Some(other_cf) = other.statistics.and_then(|s| s.change_flow) {
+                                    repos.push((other.metadata.repo, other_cf));
+                                } else {
+                                    eprintln!("Warning: {:?} has no change flow metrics — skipping", path);
+                                }
+                            }
+                            Err(e) => eprintln!("Warning: failed to load {:?}: {}", path, e),
+                        }
+                    }
+                    Some(change_flow_charts::MultiRepo { repos })
+                };
+                let chart_format = canvas::OutputFormat::from_str_or_default(&config.chart_format);
+                if let Err(e) = change_flow_charts::render_all(cf, cf_dir, window, scheme, multi.as_ref(), chart_format, config.chart_png_indexed, Some(&timeline)) {
                     eprintln!("Error rendering change flow charts: {}", e);
                     std::process::exit(1);
                 }
+                timeline.record("Change flow charts", "render_all", phase_start, phase_start.elapsed(), None);
                 eprintln!("Change flow charts written to {:?} [{:.2}s]", cf_dir, phase_start.elapsed().as_secs_f64());
+
+                if let Some(ref anim_dir) = config.cadence_animation_dir {
+                    let anim_start = Instant::now();
+                    if let Err(e) = change_flow_charts::render_release_cadence_animated(
+                        cf, anim_dir, scheme, config.cadence_animation_frames, config.cadence_animation_fps,
+                    ) {
+                        eprintln!("Error rendering release cadence animation: {}", e);
+                        std::process::exit(1);
+                    }
+                    eprintln!("Release cadence animation written [{:.2}s]", anim_start.elapsed().as_secs_f64());
+                }
             } else {
                 eprintln!("No change flow metrics in data — skipping charts");
             }
@@ -68,13 +225,41 @@ fn main() {
         }
     }
 
-    // Phase 4: Video rendering (parallel frame generation)
-    let phase_start = Instant::now();
-    if let Err(e) = render::render_video(&data, &config) {
-        eprintln!("Error rendering video: {}", e);
-        std::process::exit(1);
+    // Phase 4b: Terminal/ASCII preview of change flow charts, for CI logs or
+    // SSH sessions with no way to open a PNG.
+    if config.charts_stdout {
+        let window = change_flow_charts::DateWindow::from_args(config.since.as_deref(), config.until.as_deref());
+        let scheme = change_flow_charts::ColorScheme::from_str_or_default(&config.color_scheme);
+        if let Some(ref stats) = data.statistics {
+            if let Some(ref cf) = stats.change_flow {
+                let renderer = terminal::TerminalRenderer::new();
+                renderer.render_release_heatmap(cf, window, scheme);
+                renderer.render_velocity_drought(cf, window, scheme);
+                renderer.render_release_cadence(cf, scheme);
+                renderer.render_work_disposition(cf, scheme);
+            } else {
+                eprintln!("No change flow metrics in data — skipping terminal preview");
+            }
+        } else {
+            eprintln!("No statistics in data — skipping terminal preview");
+        }
+    }
+
+    // Phase 5: Interactive terminal statistics browser
+    if config.tui {
+        if let Err(e) = tui::run(&data) {
+            eprintln!("Error running interactive TUI: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(ref timing_path) = config.timing_output {
+        if let Err(e) = timeline.write_html(timing_path) {
+            eprintln!("Error writing timing report: {}", e);
+            std::process::exit(1);
+        }
+        eprintln!("Timing report written to {:?}", timing_path);
     }
-    eprintln!("Video rendering complete [{:.2}s]", phase_start.elapsed().as_secs_f64());
 
     eprintln!("Total elapsed: {:.2}s", total_start.elapsed().as_secs_f64());
 }
@@ -0,0 +1,150 @@
+//! Shared color quantization: median-cut palette construction with optional
+//! k-means refinement, used by the GIF encoder and indexed-PNG compression.
+
+/// A quantized color palette of at most 256 RGB entries.
+pub struct Palette {
+    pub colors: Vec<[u8; 3]>,
+}
+
+struct ColorBox {
+    pixels: Vec<[u8; 3]>,
+}
+
+impl ColorBox {
+    fn volume(&self) -> u32 {
+        let (lo, hi) = self.bounds();
+        let mut v = 1u32;
+        for c in 0..3 {
+            v *= u32::from(hi[c] - lo[c]) + 1;
+        }
+        v
+    }
+
+    fn weighted_volume(&self) -> u64 {
+        u64::from(self.volume()) * self.pixels.len() as u64
+    }
+
+    fn bounds(&self) -> ([u8; 3], [u8; 3]) {
+        let mut lo = [255u8; 3];
+        let mut hi = [0u8; 3];
+        for p in &self.pixels {
+            for c in 0..3 {
+                lo[c] = lo[c].min(p[c]);
+                hi[c] = hi[c].max(p[c]);
+            }
+        }
+        (lo, hi)
+    }
+
+    fn longest_axis(&self) -> usize {
+        let (lo, hi) = self.bounds();
+        let ranges = [hi[0] - lo[0], hi[1] - lo[1], hi[2] - lo[2]];
+        if ranges[0] >= ranges[1] && ranges[0] >= ranges[2] {
+            0
+        } else if ranges[1] >= ranges[2] {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn split(mut self) -> (ColorBox, ColorBox) {
+        let axis = self.longest_axis();
+        self.pixels.sort_unstable_by_key(|p| p[axis]);
+        let mid = self.pixels.len() / 2;
+        let b = self.pixels.split_off(mid);
+        (ColorBox { pixels: self.pixels }, ColorBox { pixels: b })
+    }
+
+    fn average(&self) -> [u8; 3] {
+        let mut sum = [0u64; 3];
+        for p in &self.pixels {
+            for c in 0..3 {
+                sum[c] += u64::from(p[c]);
+            }
+        }
+        let n = self.pixels.len().max(1) as u64;
+        [
+            (sum[0] / n) as u8,
+            (sum[1] / n) as u8,
+            (sum[2] / n) as u8,
+        ]
+    }
+}
+
+impl Palette {
+    /// Build a palette of at most `max_colors` entries from sampled pixels via
+    /// median-cut: repeatedly split the color box with the largest
+    /// count-weighted volume along its longest axis, at the median.
+    pub fn build(pixels: impl IntoIterator<Item = [u8; 3]>, max_colors: usize) -> Palette {
+        let pixels: Vec<[u8; 3]> = pixels.into_iter().collect();
+        if pixels.is_empty() {
+            return Palette { colors: vec![[0, 0, 0]] };
+        }
+
+        let mut boxes = vec![ColorBox { pixels }];
+        while boxes.len() < max_colors.max(1) {
+            let Some((idx, _)) = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| b.pixels.len() > 1)
+                .max_by_key(|(_, b)| b.weighted_volume())
+            else {
+                break;
+            };
+            let victim = boxes.swap_remove(idx);
+            let (a, b) = victim.split();
+            boxes.push(a);
+            boxes.push(b);
+        }
+
+        Palette {
+            colors: boxes.iter().map(ColorBox::average).collect(),
+        }
+    }
+
+    /// Refine palette entries with Lloyd/k-means iterations: reassign every
+    /// sampled pixel to its nearest current entry, then recompute each entry
+    /// as the mean of its assigned pixels.
+    pub fn refine_kmeans(&mut self, pixels: &[[u8; 3]], iterations: usize) {
+        if pixels.is_empty() {
+            return;
+        }
+        for _ in 0..iterations {
+            let mut sums = vec![[0u64; 3]; self.colors.len()];
+            let mut counts = vec![0u64; self.colors.len()];
+            for &p in pixels {
+                let idx = self.nearest_index(p);
+                for c in 0..3 {
+                    sums[idx][c] += u64::from(p[c]);
+                }
+                counts[idx] += 1;
+            }
+            for (i, color) in self.colors.iter_mut().enumerate() {
+                if counts[i] == 0 {
+                    continue;
+                }
+                *color = [
+                    (sums[i][0] / counts[i]) as u8,
+                    (sums[i][1] / counts[i]) as u8,
+                    (sums[i][2] / counts[i]) as u8,
+                ];
+            }
+        }
+    }
+
+    /// Index of the palette entry nearest `color` by squared Euclidean distance.
+    pub fn nearest_index(&self, color: [u8; 3]) -> usize {
+        self.colors
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, c)| {
+                let dr = i32::from(c[0]) - i32::from(color[0]);
+                let dg = i32::from(c[1]) - i32::from(color[1]);
+                let db = i32::from(c[2]) - i32::from(color[2]);
+                dr * dr + dg * dg + db * db
+            })
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    }
+}
@@ -1,15 +1,29 @@
+use crate::annotations::{self, ResolvedAnnotation};
 use crate::config::RenderConfig;
 use crate::data::CollectedData;
+use chrono::{DateTime, Utc};
+use crate::diagnostics::{Diagnostics, FrameSample};
+use crate::gif::GifEncoder;
 use crate::layout::{
-    BranchLabel, BranchVisualInfo, DateTick, NetworkLayout, PositionedCommit, PositionedMerge,
-    PositionedTag,
+    BranchLabel, BranchVisualInfo, CommitOrder, DateTick, FileFlow, NetworkLayout, PositionedCommit,
+    PositionedMerge, PositionedTag,
 };
-use crate::stats::{precompute_frame_stats, FrameStats};
-use crate::text::TextRenderer;
+use crate::palette::Palette;
+use crate::stats::{precompute_frame_stats, FrameStats, RenderFrameMetrics};
+use crate::text::{Anchor, TextRenderer, VerticalAnchor};
+use crate::timing::Timeline;
 use rayon::prelude::*;
-use std::io::Write;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::{Read, Write};
 use std::process::{Command, Stdio};
-use tiny_skia::{Color, Paint, PathBuilder, Pixmap, Stroke, Transform};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use tiny_skia::{
+    ClipMask, Color, FillRule, GradientStop, LinearGradient, Paint, PathBuilder, Pixmap, Point,
+    RadialGradient, SpreadMode, Stroke, Transform,
+};
 
 // ── Sacred Timeline palette ─────────────────────────────────────────────────
 
@@ -66,6 +80,55 @@ fn with_alpha(c: Color, a: f32) -> Color {
     Color::from_rgba(c.red(), c.green(), c.blue(), a).unwrap_or(c)
 }
 
+/// Radial gradient from `color` at full opacity in the center to fully
+/// transparent at `radius`, for volumetric commit-rectangle fills (falls
+/// back to the flat, semi-transparent fill if the shader can't be built;
+/// `radius` is clamped to at least 0.01 first).
+fn radial_fill_paint(color: Color, cx: f32, cy: f32, radius: f32) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.anti_alias = true;
+    let stops = vec![
+        GradientStop::new(0.0, with_alpha(color, 0.95)),
+        GradientStop::new(1.0, with_alpha(color, 0.0)),
+    ];
+    match RadialGradient::new(
+        Point::from_xy(cx, cy),
+        Point::from_xy(cx, cy),
+        radius.max(0.01),
+        stops,
+        SpreadMode::Pad,
+        Transform::identity(),
+    ) {
+        Some(shader) => paint.shader = shader,
+        None => paint.set_color(with_alpha(color, 0.85)),
+    }
+    paint
+}
+
+/// Vertical linear gradient for the Sacred Timeline glow band: transparent
+/// at both edges, brightest gold at the core, so the 12px band reads as a
+/// soft glow rather than a flat dim stroke.
+fn glow_gradient_paint(main_y: f32, half_height: f32) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.anti_alias = true;
+    let stops = vec![
+        GradientStop::new(0.0, with_alpha(sacred_gold(), 0.0)),
+        GradientStop::new(0.5, with_alpha(sacred_gold(), 0.55)),
+        GradientStop::new(1.0, with_alpha(sacred_gold(), 0.0)),
+    ];
+    match LinearGradient::new(
+        Point::from_xy(0.0, main_y - half_height),
+        Point::from_xy(0.0, main_y + half_height),
+        stops,
+        SpreadMode::Pad,
+        Transform::identity(),
+    ) {
+        Some(shader) => paint.shader = shader,
+        None => paint.set_color(sacred_gold_glow()),
+    }
+    paint
+}
+
 // ── Drawing helpers ─────────────────────────────────────────────────────────
 
 fn fill_rounded_rect(
@@ -127,6 +190,83 @@ fn stroke_rounded_rect(
     }
 }
 
+// ── Guard-band clipping/culling ─────────────────────────────────────────────
+
+/// Margin (px) added around the visible plot rectangle before culling or
+/// clipping, so splines/curves that dip just off-screen still enter and
+/// exit cleanly instead of being cut off exactly at the canvas edge.
+const GUARD_MARGIN: f32 = 64.0;
+
+/// The plot rectangle (`[margin_left, width-margin_right] x [0, height]`)
+/// widened by `GUARD_MARGIN` on every side. Used both to build a clip mask
+/// bounding per-frame rasterization and to cull branch/merge geometry that
+/// falls entirely outside it before it's ever turned into a path.
+struct GuardBand {
+    min_x: f32,
+    max_x: f32,
+    min_y: f32,
+    max_y: f32,
+}
+
+impl GuardBand {
+    fn new(layout: &NetworkLayout, width: u32, height: u32) -> Self {
+        GuardBand {
+            min_x: layout.margin_left - GUARD_MARGIN,
+            max_x: width as f32 - layout.margin_right + GUARD_MARGIN,
+            min_y: -GUARD_MARGIN,
+            max_y: height as f32 + GUARD_MARGIN,
+        }
+    }
+
+    fn contains(&self, p: (f32, f32)) -> bool {
+        p.0 >= self.min_x && p.0 <= self.max_x && p.1 >= self.min_y && p.1 <= self.max_y
+    }
+
+    /// Whether the axis-aligned bounding box of `(x0, y0)`-`(x1, y1)` overlaps
+    /// this band at all.
+    fn intersects_segment_bbox(&self, x0: f32, y0: f32, x1: f32, y1: f32) -> bool {
+        let (bx0, bx1) = if x0 <= x1 { (x0, x1) } else { (x1, x0) };
+        let (by0, by1) = if y0 <= y1 { (y0, y1) } else { (y1, y0) };
+        bx1 >= self.min_x && bx0 <= self.max_x && by1 >= self.min_y && by0 <= self.max_y
+    }
+
+    /// Build a `ClipMask` covering this band, bounding rasterization to the
+    /// visible plot area (plus margin) for a pixmap of the given size.
+    fn clip_mask(&self, width: u32, height: u32) -> Option<ClipMask> {
+        let mut pb = PathBuilder::new();
+        pb.move_to(self.min_x, self.min_y);
+        pb.line_to(self.max_x, self.min_y);
+        pb.line_to(self.max_x, self.max_y);
+        pb.line_to(self.min_x, self.max_y);
+        pb.close();
+        let path = pb.finish()?;
+        let mut mask = ClipMask::new();
+        mask.set_path(width, height, &path, FillRule::Winding, true)?;
+        Some(mask)
+    }
+}
+
+/// Drop interior runs of `points` that lie fully outside `band`, keeping
+/// every point that's inside plus exactly one point on each side of every
+/// boundary crossing — so a clipped spline/polyline still enters and exits
+/// the visible area correctly instead of snapping straight to the next
+/// on-screen point.
+fn cull_to_guard_band(points: &[(f32, f32)], band: &GuardBand) -> Vec<(f32, f32)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    points
+        .iter()
+        .enumerate()
+        .filter(|&(i, &p)| {
+            band.contains(p)
+                || (i > 0 && band.contains(points[i - 1]))
+                || (i + 1 < points.len() && band.contains(points[i + 1]))
+        })
+        .map(|(_, &p)| p)
+        .collect()
+}
+
 // ── Catmull-Rom spline helpers ──────────────────────────────────────────────
 
 /// Convert a Catmull-Rom segment (p0, p1, p2, p3) into cubic Bezier control points.
@@ -155,6 +295,7 @@ fn draw_catmull_rom_spline(
     points: &[(f32, f32)],
     paint: &Paint,
     stroke: &Stroke,
+    clip_mask: Option<&ClipMask>,
 ) {
     if points.len() < 2 {
         return;
@@ -164,7 +305,7 @@ fn draw_catmull_rom_spline(
         pb.move_to(points[0].0, points[0].1);
         pb.line_to(points[1].0, points[1].1);
         if let Some(path) = pb.finish() {
-            pixmap.stroke_path(&path, paint, stroke, Transform::identity(), None);
+            pixmap.stroke_path(&path, paint, stroke, Transform::identity(), clip_mask);
         }
         return;
     }
@@ -189,13 +330,19 @@ fn draw_catmull_rom_spline(
     }
 
     if let Some(path) = pb.finish() {
-        pixmap.stroke_path(&path, paint, stroke, Transform::identity(), None);
+        pixmap.stroke_path(&path, paint, stroke, Transform::identity(), clip_mask);
     }
 }
 
 // ── Legend ───────────────────────────────────────────────────────────────────
 
-fn draw_legend(pixmap: &mut Pixmap, text_renderer: &TextRenderer, _width: u32, height: u32) {
+fn draw_legend(
+    pixmap: &mut Pixmap,
+    text_renderer: &TextRenderer,
+    _width: u32,
+    height: u32,
+    visible: &[PositionedCommit],
+) {
     let legend_y = height as f32 - 95.0;
     let dim = Color::from_rgba8(160, 160, 170, 255);
     let bright = Color::from_rgba8(230, 230, 240, 255);
@@ -245,6 +392,11 @@ fn draw_legend(pixmap: &mut Pixmap, text_renderer: &TextRenderer, _width: u32, h
     fill_rounded_rect(pixmap, 290.0, size_y - 14.0, 18.0, 20.0, 2.0, &paint);
     text_renderer.draw_text(pixmap, "many files, many lines", 314.0, size_y, 10.0, dim);
 
+    // Commit-size distribution boxplot, next to the size legend
+    let box_y = size_y + 20.0;
+    text_renderer.draw_text(pixmap, "Size distribution:", 20.0, box_y, 11.0, dim);
+    draw_commit_size_boxplot(pixmap, visible, 140.0, box_y - 4.0, 250.0);
+
     // Sacred timeline indicator
     let mut gold_paint = Paint::default();
     gold_paint.set_color(sacred_gold());
@@ -329,23 +481,47 @@ fn draw_branch_labels(
 
 // ── Sacred Timeline line (main branch) ──────────────────────────────────────
 
-fn draw_sacred_timeline(pixmap: &mut Pixmap, layout: &NetworkLayout, width: u32) {
+fn draw_sacred_timeline(
+    pixmap: &mut Pixmap,
+    layout: &NetworkLayout,
+    width: u32,
+    gradient_fills: bool,
+    additive_glow: bool,
+) {
     let main_y = layout.main_y;
+    let glow_blend = if additive_glow { tiny_skia::BlendMode::Plus } else { tiny_skia::BlendMode::SourceOver };
 
-    // Outer glow (wide, dim)
-    let mut glow_paint = Paint::default();
-    glow_paint.set_color(sacred_gold_glow());
-    glow_paint.anti_alias = true;
-    let glow_stroke = Stroke {
-        width: 12.0,
-        ..Stroke::default()
-    };
+    if gradient_fills {
+        // Glow band as a filled rect with a vertical linear gradient, so it
+        // reads as a soft volumetric glow instead of a flat dim stroke.
+        let mut glow_paint = glow_gradient_paint(main_y, 6.0);
+        glow_paint.blend_mode = glow_blend;
+        let mut pb = PathBuilder::new();
+        pb.move_to(layout.margin_left, main_y - 6.0);
+        pb.line_to(width as f32 - layout.margin_right, main_y - 6.0);
+        pb.line_to(width as f32 - layout.margin_right, main_y + 6.0);
+        pb.line_to(layout.margin_left, main_y + 6.0);
+        pb.close();
+        if let Some(path) = pb.finish() {
+            pixmap.fill_path(&path, &glow_paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+        }
+    } else {
+        // Outer glow (wide, dim)
+        let mut glow_paint = Paint::default();
+        glow_paint.set_color(sacred_gold_glow());
+        glow_paint.anti_alias = true;
+        glow_paint.blend_mode = glow_blend;
+        let glow_stroke = Stroke {
+            width: 12.0,
+            ..Stroke::default()
+        };
 
-    let mut pb = PathBuilder::new();
-    pb.move_to(layout.margin_left, main_y);
-    pb.line_to(width as f32 - layout.margin_right, main_y);
-    if let Some(path) = pb.finish() {
-        pixmap.stroke_path(&path, &glow_paint, &glow_stroke, Transform::identity(), None);
+        let mut pb = PathBuilder::new();
+        pb.move_to(layout.margin_left, main_y);
+        pb.line_to(width as f32 - layout.margin_right, main_y);
+        if let Some(path) = pb.finish() {
+            pixmap.stroke_path(&path, &glow_paint, &glow_stroke, Transform::identity(), None);
+        }
     }
 
     // Core line (bright gold)
@@ -372,8 +548,10 @@ fn draw_tags(
     text_renderer: &TextRenderer,
     tags: &[PositionedTag],
     visible_x_limit: f32,
+    additive_glow: bool,
 ) {
     let gold = sacred_gold();
+    let glow_blend = if additive_glow { tiny_skia::BlendMode::Plus } else { tiny_skia::BlendMode::SourceOver };
 
     for tag in tags {
         if tag.x > visible_x_limit {
@@ -384,6 +562,7 @@ fn draw_tags(
         let mut paint = Paint::default();
         paint.set_color(with_alpha(gold, 0.6));
         paint.anti_alias = true;
+        paint.blend_mode = glow_blend;
         let stroke = Stroke {
             width: 1.5,
             ..Stroke::default()
@@ -400,6 +579,7 @@ fn draw_tags(
         let mut diamond_paint = Paint::default();
         diamond_paint.set_color(with_alpha(gold, 0.9));
         diamond_paint.anti_alias = true;
+        diamond_paint.blend_mode = glow_blend;
         let d = 5.0;
         let dy = tag.label_y + 8.0;
         let mut pb = PathBuilder::new();
@@ -431,6 +611,259 @@ fn draw_tags(
     }
 }
 
+/// Faint threads connecting a file's old and new position across a rename
+/// (`lineage::track_lineage`'s flow list), bowed upward with a 3-point
+/// Catmull-Rom spline rather than a straight line so they read as "code
+/// moved from here to there" without colliding visually with branch lines.
+fn draw_file_flows(
+    pixmap: &mut Pixmap,
+    flows: &[FileFlow],
+    positioned_commits: &[PositionedCommit],
+    visible_x_limit: f32,
+) {
+    for flow in flows {
+        let (Some(src), Some(dst)) =
+            (positioned_commits.get(flow.source_position), positioned_commits.get(flow.dest_position))
+        else {
+            continue;
+        };
+        if dst.x > visible_x_limit {
+            continue;
+        }
+
+        let mid_x = (src.x + dst.x) / 2.0;
+        let mid_y = src.y.min(dst.y) - 12.0;
+
+        let mut paint = Paint::default();
+        paint.set_color(with_alpha(Color::from_rgba8(200, 200, 220, 255), 0.25));
+        paint.anti_alias = true;
+        let stroke = Stroke { width: 1.0, ..Stroke::default() };
+
+        draw_catmull_rom_spline(
+            pixmap,
+            &[(src.x, src.y), (mid_x, mid_y), (dst.x, dst.y)],
+            &paint,
+            &stroke,
+            None,
+        );
+    }
+}
+
+// ── Commit-size distribution boxplot ────────────────────────────────────────
+
+/// Percentile via linear interpolation between ranks, applied to an
+/// already-sorted slice (the same "R type 7" definition spreadsheets use).
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+    let rank = p * (sorted.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+    if lo == hi {
+        sorted[lo]
+    } else {
+        sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+    }
+}
+
+/// Compact horizontal boxplot of the lines-changed distribution of `visible`
+/// commits: box spans Q1-Q3 with a median line, whiskers reach the most
+/// extreme non-outlier values, and commits beyond `Q1 - 1.5*IQR` /
+/// `Q3 + 1.5*IQR` (Tukey's rule) are drawn as individual dots past the
+/// whiskers. `(x, y)` is the whisker line's left end and vertical center;
+/// the whole plot is `w` px wide.
+fn draw_commit_size_boxplot(pixmap: &mut Pixmap, visible: &[PositionedCommit], x: f32, y: f32, w: f32) {
+    let mut values: Vec<f64> = visible
+        .iter()
+        .map(|pc| f64::from(pc.commit.insertions + pc.commit.deletions))
+        .collect();
+    if values.is_empty() {
+        return;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let min = values[0];
+    let max = *values.last().unwrap();
+    let q1 = percentile(&values, 0.25);
+    let median = percentile(&values, 0.5);
+    let q3 = percentile(&values, 0.75);
+    let iqr = q3 - q1;
+    let lower_fence = q1 - 1.5 * iqr;
+    let upper_fence = q3 + 1.5 * iqr;
+
+    let whisker_low = values.iter().copied().find(|&v| v >= lower_fence).unwrap_or(min);
+    let whisker_high = values.iter().copied().rev().find(|&v| v <= upper_fence).unwrap_or(max);
+    let outliers: Vec<f64> =
+        values.iter().copied().filter(|&v| v < lower_fence || v > upper_fence).collect();
+
+    let domain_min = min.min(lower_fence);
+    let domain_max = (max.max(upper_fence)).max(domain_min + 1.0);
+    let scale = |v: f64| -> f32 { x + ((v - domain_min) / (domain_max - domain_min)) as f32 * w };
+
+    let accent = threshold_color(median, 200.0, 1000.0);
+    let half_h = 7.0;
+
+    let mut whisker_paint = Paint::default();
+    whisker_paint.set_color(with_alpha(accent, 0.8));
+    whisker_paint.anti_alias = true;
+    let whisker_stroke = Stroke { width: 1.0, ..Stroke::default() };
+
+    let mut pb = PathBuilder::new();
+    pb.move_to(scale(whisker_low), y);
+    pb.line_to(scale(whisker_high), y);
+    if let Some(path) = pb.finish() {
+        pixmap.stroke_path(&path, &whisker_paint, &whisker_stroke, Transform::identity(), None);
+    }
+    for v in [whisker_low, whisker_high] {
+        let mut pb = PathBuilder::new();
+        pb.move_to(scale(v), y - half_h * 0.5);
+        pb.line_to(scale(v), y + half_h * 0.5);
+        if let Some(path) = pb.finish() {
+            pixmap.stroke_path(&path, &whisker_paint, &whisker_stroke, Transform::identity(), None);
+        }
+    }
+
+    let box_x0 = scale(q1);
+    let box_x1 = scale(q3).max(box_x0 + 1.0);
+
+    let mut box_fill_paint = Paint::default();
+    box_fill_paint.set_color(with_alpha(accent, 0.35));
+    box_fill_paint.anti_alias = true;
+    fill_rounded_rect(pixmap, box_x0, y - half_h, box_x1 - box_x0, half_h * 2.0, 1.5, &box_fill_paint);
+
+    let mut box_border_paint = Paint::default();
+    box_border_paint.set_color(with_alpha(accent, 0.9));
+    box_border_paint.anti_alias = true;
+    let box_stroke = Stroke { width: 1.0, ..Stroke::default() };
+    stroke_rounded_rect(
+        pixmap, box_x0, y - half_h, box_x1 - box_x0, half_h * 2.0, 1.5, &box_border_paint, &box_stroke,
+    );
+
+    let mut median_paint = Paint::default();
+    median_paint.set_color(accent);
+    median_paint.anti_alias = true;
+    let median_stroke = Stroke { width: 1.5, ..Stroke::default() };
+    let mut pb = PathBuilder::new();
+    pb.move_to(scale(median), y - half_h);
+    pb.line_to(scale(median), y + half_h);
+    if let Some(path) = pb.finish() {
+        pixmap.stroke_path(&path, &median_paint, &median_stroke, Transform::identity(), None);
+    }
+
+    let mut outlier_paint = Paint::default();
+    outlier_paint.set_color(with_alpha(accent, 0.7));
+    outlier_paint.anti_alias = true;
+    for v in outliers {
+        let mut pb = PathBuilder::new();
+        pb.push_circle(scale(v), y, 1.8);
+        if let Some(path) = pb.finish() {
+            pixmap.fill_path(&path, &outlier_paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+        }
+    }
+}
+
+// ── Commit inspector footer ──────────────────────────────────────────────────
+
+/// Which commit `draw_commit_footer` spotlights.
+enum CommitFocus {
+    /// The newest currently-visible commit (`visible.last()`).
+    Latest,
+    /// A specific commit index into `CollectedData::commits`, for a caller
+    /// driving an animation that wants to pin the footer to one commit
+    /// instead of always tracking the newest.
+    Index(usize),
+}
+
+/// Parse `--inspector-commit`: `"latest"` (or anything non-numeric) means
+/// track the newest visible commit each frame; a decimal string pins the
+/// footer to that commit index.
+fn parse_commit_focus(s: &str) -> CommitFocus {
+    match s.trim().parse::<usize>() {
+        Ok(i) => CommitFocus::Index(i),
+        Err(_) => CommitFocus::Latest,
+    }
+}
+
+/// Detail strip for the focused commit, mirroring the file-stats footer
+/// pattern: short hash, relative age, category chip, files/lines touched,
+/// and any tag names. `Commit` carries no per-commit author, so that field
+/// is omitted here rather than invented.
+fn draw_commit_footer(
+    pixmap: &mut Pixmap,
+    text_renderer: &TextRenderer,
+    data: &CollectedData,
+    layout: &NetworkLayout,
+    positioned_commits: &[PositionedCommit],
+    visible_count: usize,
+    focus: &CommitFocus,
+    width: u32,
+) {
+    let idx = match *focus {
+        CommitFocus::Latest => visible_count.checked_sub(1),
+        CommitFocus::Index(i) => (i < visible_count).then_some(i),
+    };
+    let Some(pc) = idx.and_then(|i| positioned_commits.get(i)) else { return };
+    let commit = pc.commit;
+
+    let newest = data.commits.iter().map(|c| c.timestamp).max().unwrap_or(commit.timestamp);
+    let age_days = (newest - commit.timestamp).num_days();
+
+    let footer_h: f32 = 34.0;
+    let footer_x = layout.margin_left;
+    let footer_y = layout.main_y + 30.0;
+    let footer_w = width as f32 - layout.margin_right - layout.margin_left;
+
+    let mut bg_paint = Paint::default();
+    bg_paint.set_color(Color::from_rgba8(18, 18, 24, 200));
+    bg_paint.anti_alias = true;
+    fill_rounded_rect(pixmap, footer_x, footer_y, footer_w, footer_h, 5.0, &bg_paint);
+
+    let mut border_paint = Paint::default();
+    border_paint.set_color(with_alpha(sacred_gold(), 0.4));
+    border_paint.anti_alias = true;
+    let border_stroke = Stroke { width: 1.0, ..Stroke::default() };
+    stroke_rounded_rect(pixmap, footer_x, footer_y, footer_w, footer_h, 5.0, &border_paint, &border_stroke);
+
+    let dim = Color::from_rgba8(160, 160, 170, 255);
+    let bright = Color::from_rgba8(230, 230, 240, 255);
+    let text_y = footer_y + footer_h / 2.0 + 4.0;
+    let mut x = footer_x + 12.0;
+
+    let short_sha = &commit.sha[..commit.sha.len().min(7)];
+    text_renderer.draw_text(pixmap, short_sha, x, text_y, 11.0, bright);
+    x += text_renderer.measure_text(short_sha, 11.0) + 14.0;
+
+    let mut chip_paint = Paint::default();
+    chip_paint.set_color(category_color(&commit.category));
+    chip_paint.anti_alias = true;
+    fill_rounded_rect(pixmap, x, text_y - 9.0, 10.0, 10.0, 2.0, &chip_paint);
+    x += 14.0;
+    text_renderer.draw_text(pixmap, &commit.category, x, text_y, 11.0, dim);
+    x += text_renderer.measure_text(&commit.category, 11.0) + 14.0;
+
+    let age_label = if age_days <= 0 { "today".to_string() } else { format!("{age_days}d ago") };
+    text_renderer.draw_text(pixmap, &age_label, x, text_y, 11.0, dim);
+    x += text_renderer.measure_text(&age_label, 11.0) + 14.0;
+
+    let files_label = format!("{} files", commit.files_changed);
+    text_renderer.draw_text(pixmap, &files_label, x, text_y, 11.0, dim);
+    x += text_renderer.measure_text(&files_label, 11.0) + 14.0;
+
+    let plus_label = format!("+{}", commit.insertions);
+    text_renderer.draw_text(pixmap, &plus_label, x, text_y, 11.0, Color::from_rgba8(129, 199, 132, 255));
+    x += text_renderer.measure_text(&plus_label, 11.0) + 6.0;
+
+    let minus_label = format!("-{}", commit.deletions);
+    text_renderer.draw_text(pixmap, &minus_label, x, text_y, 11.0, Color::from_rgba8(239, 83, 80, 255));
+    x += text_renderer.measure_text(&minus_label, 11.0) + 14.0;
+
+    if !commit.tags.is_empty() {
+        let tags_label = commit.tags.join(", ");
+        text_renderer.draw_text(pixmap, &tags_label, x, text_y, 11.0, with_alpha(sacred_gold(), 0.9));
+    }
+}
+
 // ── Stats overlay ───────────────────────────────────────────────────────────
 
 fn threshold_color(value: f64, yellow_threshold: f64, red_threshold: f64) -> Color {
@@ -567,6 +1000,231 @@ fn format_number(n: u64) -> String {
     }
 }
 
+// ── Analytics strip (time-series area charts) ───────────────────────────────
+
+/// Number of evenly spaced time buckets the analytics strip bins history
+/// into, spanning the repo's full date range regardless of how much of it
+/// is visible yet.
+const ANALYTICS_BUCKETS: usize = 36;
+
+/// One bucketed sample of history up to the current frame's timestamp, used
+/// to build the analytics strip's area charts.
+#[derive(Clone, Copy, Default)]
+struct AnalyticsBucket {
+    /// Merges landing in this bucket, normalized to a per-week rate.
+    merge_throughput: f64,
+    /// Lines changed in this bucket by commits on branches still unmerged
+    /// as of the current frame.
+    unmerged_lines: u64,
+    /// Distinct branches with a commit in this bucket.
+    active_branches: u32,
+}
+
+/// Bin commits/merges into `ANALYTICS_BUCKETS` evenly spaced buckets
+/// spanning the repo's full date range, accumulating each metric per bucket
+/// up to `now` (the timestamp of the latest visible commit this frame).
+fn compute_analytics_buckets(
+    data: &CollectedData,
+    default_branch: &str,
+    now: DateTime<Utc>,
+) -> Vec<AnalyticsBucket> {
+    let Some(start) = data.commits.iter().map(|c| c.timestamp).min() else {
+        return Vec::new();
+    };
+    let end = data.commits.iter().map(|c| c.timestamp).max().unwrap_or(start);
+    let span_secs = (end - start).num_seconds().max(1) as f64;
+    let bucket_secs = (span_secs / ANALYTICS_BUCKETS as f64).max(1.0);
+
+    let merge_shas: std::collections::HashSet<&str> =
+        data.merges.iter().map(|m| m.sha.as_str()).collect();
+    let merge_from: std::collections::HashMap<&str, &str> =
+        data.merges.iter().map(|m| (m.sha.as_str(), m.from_branch.as_str())).collect();
+
+    // Branches merged by `now`, same rule as stats::precompute_frame_stats:
+    // the default branch always counts as merged, others flip once their
+    // merge commit has landed.
+    let mut merged_branches: std::collections::HashSet<&str> = std::collections::HashSet::new();
+    merged_branches.insert(default_branch);
+    for c in &data.commits {
+        if c.timestamp > now {
+            continue;
+        }
+        if merge_shas.contains(c.sha.as_str()) {
+            if let Some(from) = merge_from.get(c.sha.as_str()) {
+                merged_branches.insert(*from);
+            }
+        }
+    }
+
+    let mut buckets = vec![AnalyticsBucket::default(); ANALYTICS_BUCKETS];
+    let mut bucket_branches: Vec<std::collections::HashSet<&str>> =
+        vec![std::collections::HashSet::new(); ANALYTICS_BUCKETS];
+
+    for c in &data.commits {
+        if c.timestamp > now {
+            continue;
+        }
+        let offset_secs = (c.timestamp - start).num_seconds() as f64;
+        let idx = ((offset_secs / bucket_secs) as usize).min(ANALYTICS_BUCKETS - 1);
+
+        if merge_shas.contains(c.sha.as_str()) {
+            buckets[idx].merge_throughput += 1.0;
+        }
+        if !merged_branches.contains(c.branch.as_str()) {
+            buckets[idx].unmerged_lines += u64::from(c.insertions + c.deletions);
+            bucket_branches[idx].insert(c.branch.as_str());
+        }
+    }
+
+    let bucket_days = bucket_secs / 86400.0;
+    for (i, b) in buckets.iter_mut().enumerate() {
+        b.active_branches = bucket_branches[i].len() as u32;
+        if bucket_days > 0.0 {
+            b.merge_throughput = b.merge_throughput / bucket_days * 7.0;
+        }
+    }
+
+    buckets
+}
+
+/// Draw one filled area chart (baseline → points → baseline, translucent
+/// gradient fill, stroked top edge) inside the box `(x, y, w, h)`. The most
+/// recent bucket is marked with a dot in `accent` (normally a
+/// `threshold_color` tint of the latest value) so the strip reads as "here's
+/// where we are right now" as well as a trend.
+fn draw_area_chart(pixmap: &mut Pixmap, x: f32, y: f32, w: f32, h: f32, values: &[f64], accent: Color) {
+    let n = values.len();
+    if n == 0 {
+        return;
+    }
+    let max_val = values.iter().cloned().fold(0.0_f64, f64::max).max(1.0);
+    let step = if n > 1 { w / (n - 1) as f32 } else { 0.0 };
+    let point_at = |i: usize| -> (f32, f32) {
+        (x + step * i as f32, y + h - (values[i] / max_val) as f32 * h)
+    };
+
+    let mut fill_pb = PathBuilder::new();
+    fill_pb.move_to(x, y + h);
+    for i in 0..n {
+        let (vx, vy) = point_at(i);
+        fill_pb.line_to(vx, vy);
+    }
+    fill_pb.line_to(point_at(n - 1).0, y + h);
+    fill_pb.close();
+
+    if let Some(path) = fill_pb.finish() {
+        let mut fill_paint = Paint::default();
+        fill_paint.anti_alias = true;
+        let stops = vec![
+            GradientStop::new(0.0, with_alpha(accent, 0.45)),
+            GradientStop::new(1.0, with_alpha(accent, 0.0)),
+        ];
+        match LinearGradient::new(
+            Point::from_xy(x, y), Point::from_xy(x, y + h), stops, SpreadMode::Pad, Transform::identity(),
+        ) {
+            Some(shader) => fill_paint.shader = shader,
+            None => fill_paint.set_color(with_alpha(accent, 0.3)),
+        }
+        pixmap.fill_path(&path, &fill_paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+    }
+
+    let mut line_pb = PathBuilder::new();
+    line_pb.move_to(point_at(0).0, point_at(0).1);
+    for i in 1..n {
+        let (vx, vy) = point_at(i);
+        line_pb.line_to(vx, vy);
+    }
+    if let Some(path) = line_pb.finish() {
+        let mut line_paint = Paint::default();
+        line_paint.set_color(with_alpha(accent, 0.85));
+        line_paint.anti_alias = true;
+        let stroke = Stroke { width: 1.5, ..Stroke::default() };
+        pixmap.stroke_path(&path, &line_paint, &stroke, Transform::identity(), None);
+    }
+
+    let (lx, ly) = point_at(n - 1);
+    let mut dot_paint = Paint::default();
+    dot_paint.set_color(accent);
+    dot_paint.anti_alias = true;
+    let mut dot_pb = PathBuilder::new();
+    dot_pb.push_circle(lx, ly, 2.5);
+    if let Some(path) = dot_pb.finish() {
+        pixmap.fill_path(&path, &dot_paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+    }
+}
+
+/// Trend strip: filled area charts of merge throughput, unmerged-line count,
+/// and active-branch count across the repo's history, revealed up to the
+/// current frame. Complements `draw_stats_overlay`'s instantaneous counts
+/// with a sense of where those numbers came from and where they're headed.
+fn draw_analytics_strip(
+    pixmap: &mut Pixmap,
+    text_renderer: &TextRenderer,
+    data: &CollectedData,
+    default_branch: &str,
+    now: DateTime<Utc>,
+    height: u32,
+) {
+    let buckets = compute_analytics_buckets(data, default_branch, now);
+    if buckets.is_empty() {
+        return;
+    }
+
+    let strip_w: f32 = 360.0;
+    let chart_h: f32 = 38.0;
+    let chart_gap: f32 = 30.0;
+    let strip_h: f32 = chart_gap * 3.0 + chart_h * 3.0 + 10.0;
+    let strip_x: f32 = 20.0;
+    let strip_y: f32 = height as f32 - 95.0 - strip_h - 20.0;
+
+    let mut bg_paint = Paint::default();
+    bg_paint.set_color(Color::from_rgba8(18, 18, 24, 200));
+    bg_paint.anti_alias = true;
+    fill_rounded_rect(pixmap, strip_x, strip_y, strip_w, strip_h, 6.0, &bg_paint);
+
+    let mut border_paint = Paint::default();
+    border_paint.set_color(with_alpha(sacred_gold(), 0.5));
+    border_paint.anti_alias = true;
+    let border_stroke = Stroke { width: 1.5, ..Stroke::default() };
+    stroke_rounded_rect(pixmap, strip_x, strip_y, strip_w, strip_h, 6.0, &border_paint, &border_stroke);
+
+    let dim = Color::from_rgba8(160, 160, 170, 255);
+    let series: [(&str, Vec<f64>, Color, f64, f64); 3] = [
+        (
+            "Merge throughput (/wk)",
+            buckets.iter().map(|b| b.merge_throughput).collect(),
+            Color::from_rgba8(100, 181, 246, 255),
+            3.0,
+            8.0,
+        ),
+        (
+            "Unmerged lines",
+            buckets.iter().map(|b| b.unmerged_lines as f64).collect(),
+            Color::from_rgba8(186, 104, 200, 255),
+            1000.0,
+            5000.0,
+        ),
+        (
+            "Active branches",
+            buckets.iter().map(|b| f64::from(b.active_branches)).collect(),
+            Color::from_rgba8(129, 199, 132, 255),
+            3.0,
+            8.0,
+        ),
+    ];
+
+    for (i, (label, values, base_color, yellow, red)) in series.iter().enumerate() {
+        let chart_y = strip_y + 12.0 + i as f32 * (chart_h + chart_gap);
+        text_renderer.draw_text(pixmap, label, strip_x + 12.0, chart_y - 4.0, 10.0, dim);
+
+        // Tint the most recent bucket with `threshold_color`, except for
+        // active-branch count where "more" isn't inherently bad.
+        let latest = values.last().copied().unwrap_or(0.0);
+        let accent = if *label == "Active branches" { *base_color } else { threshold_color(latest, *yellow, *red) };
+        draw_area_chart(pixmap, strip_x + 12.0, chart_y, strip_w - 24.0, chart_h, values, accent);
+    }
+}
+
 // ── Title bar ───────────────────────────────────────────────────────────────
 
 fn draw_title(pixmap: &mut Pixmap, text_renderer: &TextRenderer, data: &CollectedData) {
@@ -592,8 +1250,188 @@ fn draw_title(pixmap: &mut Pixmap, text_renderer: &TextRenderer, data: &Collecte
     text_renderer.draw_text(pixmap, &stats, stats_x, 28.0, 12.0, dim);
 }
 
+// ── Frame cache ──────────────────────────────────────────────────────────────
+
+/// Cache hit/miss counters for a render pass, reported alongside the
+/// existing video-rendering log line.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CacheStats {
+    pub hits: u32,
+    pub misses: u32,
+}
+
+/// Stable content hash over everything that determines a frame's pixels:
+/// style, dimensions, fps, the full branch set, and the slice of commits and
+/// merges visible at `visible_count`. `DefaultHasher` (unlike `HashMap`'s
+/// `RandomState`) uses fixed keys, so the hash is stable across process runs.
+fn frame_cache_key(config: &RenderConfig, data: &CollectedData, visible_count: usize) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    config.style.hash(&mut hasher);
+    config.width.hash(&mut hasher);
+    config.height.hash(&mut hasher);
+    config.fps.hash(&mut hasher);
+    config.gradient_fills.hash(&mut hasher);
+    config.additive_glow.hash(&mut hasher);
+    config.inspector_commit.hash(&mut hasher);
+    visible_count.hash(&mut hasher);
+
+    for branch in &data.branches {
+        branch.name.hash(&mut hasher);
+        branch.is_default.hash(&mut hasher);
+    }
+
+    let visible = &data.commits[..visible_count.min(data.commits.len())];
+    let visible_shas: std::collections::HashSet<&str> =
+        visible.iter().map(|c| c.sha.as_str()).collect();
+
+    for commit in visible {
+        commit.sha.hash(&mut hasher);
+        commit.branch.hash(&mut hasher);
+        commit.category.hash(&mut hasher);
+        commit.tags.hash(&mut hasher);
+        commit.insertions.hash(&mut hasher);
+        commit.deletions.hash(&mut hasher);
+        commit.files_changed.hash(&mut hasher);
+    }
+
+    for merge in &data.merges {
+        if visible_shas.contains(merge.sha.as_str()) {
+            merge.sha.hash(&mut hasher);
+            merge.from_branch.hash(&mut hasher);
+        }
+    }
+
+    hasher.finish()
+}
+
+/// Draw whichever `annotations` are active at `frame_index` as centered
+/// banner cards near the top of the frame, below the title. Each card fades
+/// in/holds/fades out per `ResolvedAnnotation::alpha_at`; more than one can
+/// be active at once (stacked top-to-bottom), though annotation spacing is
+/// expected to keep that rare.
+fn draw_annotation_overlay(
+    pixmap: &mut Pixmap,
+    text_renderer: &TextRenderer,
+    annotations: &[ResolvedAnnotation],
+    frame_index: u32,
+    width: u32,
+) {
+    let card_w = width as f32 * 0.6;
+    let card_h: f32 = 50.0;
+    let card_x = (width as f32 - card_w) / 2.0;
+    let mut y = 70.0;
+
+    for ann in annotations {
+        let Some(alpha) = ann.alpha_at(frame_index) else { continue };
+
+        let mut bg_paint = Paint::default();
+        bg_paint.set_color(with_alpha(Color::from_rgba8(18, 18, 24, 255), 0.85 * alpha));
+        bg_paint.anti_alias = true;
+        fill_rounded_rect(pixmap, card_x, y, card_w, card_h, 6.0, &bg_paint);
+
+        let mut border_paint = Paint::default();
+        border_paint.set_color(with_alpha(sacred_gold(), alpha));
+        border_paint.anti_alias = true;
+        let border_stroke = Stroke { width: 1.5, ..Stroke::default() };
+        stroke_rounded_rect(pixmap, card_x, y, card_w, card_h, 6.0, &border_paint, &border_stroke);
+
+        text_renderer.draw_text_ex(
+            pixmap,
+            &ann.text,
+            card_x + card_w / 2.0,
+            y + card_h / 2.0 - 8.0,
+            15.0,
+            with_alpha(Color::from_rgba8(230, 230, 240, 255), alpha),
+            Anchor::Middle,
+            VerticalAnchor::Top,
+            Some(card_w - 24.0),
+        );
+
+        y += card_h + 12.0;
+    }
+}
+
+/// `--debug-overlay` HUD: a small rolling frame-time line graph in the top
+/// right corner, colored per-segment — green while a frame's render time
+/// stayed under `threshold_secs`, red when it didn't — so expensive
+/// commits/layout densities stand out while scrubbing through a render.
+fn draw_debug_overlay(
+    pixmap: &mut Pixmap,
+    text_renderer: &TextRenderer,
+    recent_render_secs: &[f64],
+    threshold_secs: f32,
+    width: u32,
+) {
+    if recent_render_secs.is_empty() {
+        return;
+    }
+
+    let graph_w: f32 = 200.0;
+    let graph_h: f32 = 50.0;
+    let x0 = width as f32 - graph_w - 20.0;
+    let y0 = 20.0;
+
+    let mut bg_paint = Paint::default();
+    bg_paint.set_color(with_alpha(Color::from_rgba8(18, 18, 24, 255), 0.75));
+    bg_paint.anti_alias = true;
+    fill_rounded_rect(pixmap, x0, y0, graph_w, graph_h, 4.0, &bg_paint);
+
+    let mut border_paint = Paint::default();
+    border_paint.set_color(with_alpha(Color::from_rgba8(160, 160, 170, 255), 0.6));
+    border_paint.anti_alias = true;
+    let border_stroke = Stroke { width: 1.0, ..Stroke::default() };
+    stroke_rounded_rect(pixmap, x0, y0, graph_w, graph_h, 4.0, &border_paint, &border_stroke);
+
+    let max_secs = recent_render_secs
+        .iter()
+        .copied()
+        .fold(threshold_secs as f64 * 1.5, f64::max);
+    let plot_w = graph_w - 8.0;
+    let plot_h = graph_h - 16.0;
+    let n = recent_render_secs.len();
+    let step = if n > 1 { plot_w / (n - 1) as f32 } else { 0.0 };
+
+    let point = |i: usize, secs: f64| -> (f32, f32) {
+        let px = x0 + 4.0 + i as f32 * step;
+        let norm = (secs / max_secs).min(1.0) as f32;
+        let py = y0 + graph_h - 4.0 - norm * plot_h;
+        (px, py)
+    };
+
+    let mut prev = point(0, recent_render_secs[0]);
+    for (i, &secs) in recent_render_secs.iter().enumerate().skip(1) {
+        let cur = point(i, secs);
+        let mut seg = PathBuilder::new();
+        seg.move_to(prev.0, prev.1);
+        seg.line_to(cur.0, cur.1);
+        if let Some(seg_path) = seg.finish() {
+            let mut line_paint = Paint::default();
+            line_paint.set_color(if secs > threshold_secs as f64 {
+                Color::from_rgba8(239, 83, 80, 255)
+            } else {
+                Color::from_rgba8(102, 187, 106, 255)
+            });
+            line_paint.anti_alias = true;
+            let stroke = Stroke { width: 1.5, ..Stroke::default() };
+            pixmap.stroke_path(&seg_path, &line_paint, &stroke, Transform::identity(), None);
+        }
+        prev = cur;
+    }
+
+    text_renderer.draw_text(
+        pixmap,
+        "frame time",
+        x0 + 6.0,
+        y0 + 12.0,
+        9.0,
+        Color::from_rgba8(160, 160, 170, 255),
+    );
+}
+
 // ── Main frame render ───────────────────────────────────────────────────────
 
+#[allow(clippy::too_many_arguments)]
 fn render_frame(
     layout: &NetworkLayout,
     positioned_commits: &[PositionedCommit],
@@ -602,13 +1440,20 @@ fn render_frame(
     date_ticks: &[DateTick],
     positioned_tags: &[PositionedTag],
     _branch_infos: &[BranchVisualInfo],
+    file_flows: &[FileFlow],
     frame_stats: Option<&FrameStats>,
     text_renderer: &TextRenderer,
     data: &CollectedData,
     visible_count: usize,
     width: u32,
     height: u32,
-) -> Pixmap {
+    frame_index: u32,
+    gradient_fills: bool,
+    additive_glow: bool,
+    inspector_commit: &str,
+    annotations: &[ResolvedAnnotation],
+    debug_overlay: Option<(&Diagnostics, f32)>,
+) -> (Pixmap, RenderFrameMetrics) {
     let mut pixmap = Pixmap::new(width, height).unwrap();
     pixmap.fill(Color::from_rgba8(18, 18, 24, 255));
 
@@ -619,19 +1464,32 @@ fn render_frame(
     draw_date_axis(&mut pixmap, text_renderer, date_ticks);
 
     // Sacred Timeline (golden main branch line)
-    draw_sacred_timeline(&mut pixmap, layout, width);
+    draw_sacred_timeline(&mut pixmap, layout, width, gradient_fills, additive_glow);
 
     let visible = &positioned_commits[..visible_count.min(positioned_commits.len())];
     let visible_x_limit = visible.last().map_or(0.0, |c| c.x);
 
     // Tags above main
-    draw_tags(&mut pixmap, text_renderer, positioned_tags, visible_x_limit);
+    draw_tags(&mut pixmap, text_renderer, positioned_tags, visible_x_limit, additive_glow);
+
+    // Rename lineage threads (faint, behind branch labels/splines)
+    draw_file_flows(&mut pixmap, file_flows, positioned_commits, visible_x_limit);
 
     // Branch labels
     draw_branch_labels(&mut pixmap, text_renderer, branch_labels, visible_x_limit);
 
+    let labels_drawn = branch_labels.iter().filter(|bl| bl.x <= visible_x_limit).count() as u32
+        + positioned_tags.iter().filter(|t| t.x <= visible_x_limit).count() as u32;
+
     // ── Draw branch splines (Catmull-Rom through branch commit positions) ───
 
+    // Guard-band clip mask: bounds rasterization to the visible plot area
+    // (plus a margin) so stroking tens of thousands of off-screen commit
+    // points doesn't cost more than it needs to.
+    let guard_band = GuardBand::new(layout, width, height);
+    let clip_mask = guard_band.clip_mask(width, height);
+    let clip_mask = clip_mask.as_ref();
+
     let mut branch_commits: std::collections::HashMap<&str, Vec<usize>> =
         std::collections::HashMap::new();
     for (i, pc) in visible.iter().enumerate() {
@@ -641,10 +1499,12 @@ fn render_frame(
             .push(i);
     }
 
+    let mut branch_lines_drawn: u32 = 0;
     for (branch_name, indices) in &branch_commits {
         if indices.len() < 2 {
             continue;
         }
+        branch_lines_drawn += 1;
 
         let is_default = *branch_name == layout.default_branch;
         let first_pc = &visible[indices[0]];
@@ -667,8 +1527,14 @@ fn render_frame(
             ..Stroke::default()
         };
 
-        // Collect points for spline
+        // Collect points for spline, then drop runs that fall entirely
+        // outside the guard band (keeping one point on each side of every
+        // crossing so the clipped line/curve still enters and exits right).
         let points: Vec<(f32, f32)> = indices.iter().map(|&i| (visible[i].x, visible[i].y)).collect();
+        let points = cull_to_guard_band(&points, &guard_band);
+        if points.len() < 2 {
+            continue;
+        }
 
         if is_default {
             // Default branch is straight — just draw line segments
@@ -678,7 +1544,7 @@ fn render_frame(
                 pb.line_to(p.0, p.1);
             }
             if let Some(path) = pb.finish() {
-                pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+                pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), clip_mask);
             }
         } else {
             // For non-default branches, prepend a point at main_y (departure)
@@ -689,7 +1555,7 @@ fn render_frame(
             spline_points.push((points[0].0, layout.main_y));
             spline_points.extend_from_slice(&points);
 
-            draw_catmull_rom_spline(&mut pixmap, &spline_points, &paint, &stroke);
+            draw_catmull_rom_spline(&mut pixmap, &spline_points, &paint, &stroke, clip_mask);
         }
     }
 
@@ -700,10 +1566,17 @@ fn render_frame(
         ..Stroke::default()
     };
 
+    let mut merge_markers_drawn: u32 = 0;
     for m in positioned_merges {
         if m.to_x > visible_x_limit {
             continue;
         }
+        // Skip merges whose whole bounding box falls outside the guard band
+        // — the curve between them can't touch the visible area either.
+        if !guard_band.intersects_segment_bbox(m.from_x, m.from_y, m.to_x, m.to_y) {
+            continue;
+        }
+        merge_markers_drawn += 1;
 
         let bc = branch_color(m.slot, m.has_conflicts, m.is_stale);
         let mut paint = Paint::default();
@@ -716,13 +1589,15 @@ fn render_frame(
         pb.cubic_to(mid_x, m.from_y, mid_x, m.to_y, m.to_x, m.to_y);
 
         if let Some(path) = pb.finish() {
-            pixmap.stroke_path(&path, &paint, &merge_stroke, Transform::identity(), None);
+            pixmap.stroke_path(&path, &paint, &merge_stroke, Transform::identity(), clip_mask);
         }
 
         // Merge point indicator: small diamond at the merge destination
         let mut merge_paint = Paint::default();
         merge_paint.set_color(with_alpha(sacred_gold(), 0.8));
         merge_paint.anti_alias = true;
+        merge_paint.blend_mode =
+            if additive_glow { tiny_skia::BlendMode::Plus } else { tiny_skia::BlendMode::SourceOver };
         let d = 4.0;
         let mut pb = PathBuilder::new();
         pb.move_to(m.to_x, m.to_y - d);
@@ -749,9 +1624,14 @@ fn render_frame(
         let half_h = pc.rect_h / 2.0;
 
         // Fill
-        let mut paint = Paint::default();
-        paint.set_color(with_alpha(color, 0.85));
-        paint.anti_alias = true;
+        let paint = if gradient_fills {
+            radial_fill_paint(color, pc.x, pc.y, half_w.max(half_h))
+        } else {
+            let mut paint = Paint::default();
+            paint.set_color(with_alpha(color, 0.85));
+            paint.anti_alias = true;
+            paint
+        };
         fill_rounded_rect(
             &mut pixmap,
             pc.x - half_w,
@@ -810,24 +1690,553 @@ fn render_frame(
         draw_stats_overlay(&mut pixmap, text_renderer, fs, width);
     }
 
+    // Analytics strip: area-chart trends up through the current frame
+    if let Some(now) = visible.last().map(|pc| pc.commit.timestamp) {
+        draw_analytics_strip(&mut pixmap, text_renderer, data, &layout.default_branch, now, height);
+    }
+
+    // Commit inspector footer: detail readout for the focused commit
+    let focus = parse_commit_focus(inspector_commit);
+    draw_commit_footer(&mut pixmap, text_renderer, data, layout, positioned_commits, visible.len(), &focus, width);
+
     // Legend
-    draw_legend(&mut pixmap, text_renderer, width, height);
+    draw_legend(&mut pixmap, text_renderer, width, height, visible);
+
+    // Milestone annotation cards, drawn last so they sit on top of everything
+    draw_annotation_overlay(&mut pixmap, text_renderer, annotations, frame_index, width);
+
+    // Debug HUD: rolling frame-time graph, drawn over everything else
+    if let Some((diagnostics, threshold_secs)) = debug_overlay {
+        let recent = diagnostics.recent_render_secs(120);
+        draw_debug_overlay(&mut pixmap, text_renderer, &recent, threshold_secs, width);
+    }
+
+    let (bounds_width, bounds_height) = if visible.is_empty() {
+        (0.0, 0.0)
+    } else {
+        let min_x = visible.iter().map(|pc| pc.x - pc.rect_w / 2.0).fold(f32::MAX, f32::min);
+        let max_x = visible.iter().map(|pc| pc.x + pc.rect_w / 2.0).fold(f32::MIN, f32::max);
+        let min_y = visible.iter().map(|pc| pc.y - pc.rect_h / 2.0).fold(f32::MAX, f32::min);
+        let max_y = visible.iter().map(|pc| pc.y + pc.rect_h / 2.0).fold(f32::MIN, f32::max);
+        (max_x - min_x, max_y - min_y)
+    };
+
+    let metrics = RenderFrameMetrics {
+        frame_index,
+        commits_drawn: visible.len() as u32,
+        branch_lines_drawn,
+        merge_markers_drawn,
+        labels_drawn,
+        bounds_width,
+        bounds_height,
+    };
+
+    (pixmap, metrics)
+}
+
+/// Recompute a frame's `RenderFrameMetrics` without drawing anything — used
+/// on a frame-cache hit, where the pixels are already on disk and only the
+/// render-complexity bookkeeping still needs to happen.
+fn compute_frame_metrics(
+    positioned_commits: &[PositionedCommit],
+    positioned_merges: &[PositionedMerge],
+    branch_labels: &[BranchLabel],
+    positioned_tags: &[PositionedTag],
+    visible_count: usize,
+    frame_index: u32,
+) -> RenderFrameMetrics {
+    let visible = &positioned_commits[..visible_count.min(positioned_commits.len())];
+    let visible_x_limit = visible.last().map_or(0.0, |c| c.x);
+
+    let labels_drawn = branch_labels.iter().filter(|bl| bl.x <= visible_x_limit).count() as u32
+        + positioned_tags.iter().filter(|t| t.x <= visible_x_limit).count() as u32;
+
+    let mut branch_commits: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    for pc in visible {
+        *branch_commits.entry(&pc.commit.branch).or_default() += 1;
+    }
+    let branch_lines_drawn = branch_commits.values().filter(|&&n| n >= 2).count() as u32;
+
+    let merge_markers_drawn = positioned_merges
+        .iter()
+        .filter(|m| m.to_x <= visible_x_limit)
+        .count() as u32;
+
+    let (bounds_width, bounds_height) = if visible.is_empty() {
+        (0.0, 0.0)
+    } else {
+        let min_x = visible.iter().map(|pc| pc.x - pc.rect_w / 2.0).fold(f32::MAX, f32::min);
+        let max_x = visible.iter().map(|pc| pc.x + pc.rect_w / 2.0).fold(f32::MIN, f32::max);
+        let min_y = visible.iter().map(|pc| pc.y - pc.rect_h / 2.0).fold(f32::MAX, f32::min);
+        let max_y = visible.iter().map(|pc| pc.y + pc.rect_h / 2.0).fold(f32::MIN, f32::max);
+        (max_x - min_x, max_y - min_y)
+    };
+
+    RenderFrameMetrics {
+        frame_index,
+        commits_drawn: visible.len() as u32,
+        branch_lines_drawn,
+        merge_markers_drawn,
+        labels_drawn,
+        bounds_width,
+        bounds_height,
+    }
+}
+
+// ── Intro/outro title cards ──────────────────────────────────────────────────
+
+/// Render a static title card: centered text lines over the same background
+/// color as the animated frames, so the card cuts in cleanly. `lines` is
+/// `(text, font size, color)` drawn top-to-bottom, vertically centered as a
+/// block.
+fn render_title_card(
+    text_renderer: &TextRenderer,
+    width: u32,
+    height: u32,
+    lines: &[(String, f32, Color)],
+) -> Pixmap {
+    let mut pixmap = Pixmap::new(width, height).unwrap();
+    pixmap.fill(Color::from_rgba8(18, 18, 24, 255));
+
+    let line_gap = 14.0;
+    let total_h: f32 = lines.iter().map(|(_, size, _)| size * 1.2).sum::<f32>()
+        + line_gap * lines.len().saturating_sub(1) as f32;
+    let mut y = (height as f32 - total_h) / 2.0;
+
+    for (text, size, color) in lines {
+        text_renderer.draw_text_ex(
+            &mut pixmap,
+            text,
+            width as f32 / 2.0,
+            y,
+            *size,
+            *color,
+            Anchor::Middle,
+            VerticalAnchor::Top,
+            Some(width as f32 - 80.0),
+        );
+        y += size * 1.2 + line_gap;
+    }
 
     pixmap
 }
 
+/// Intro card: repository name and the commit date range it covers.
+fn render_intro_card(text_renderer: &TextRenderer, data: &CollectedData, width: u32, height: u32) -> Pixmap {
+    let bright = Color::from_rgba8(230, 230, 240, 255);
+    let dim = Color::from_rgba8(140, 140, 150, 255);
+
+    let repo_name = &data.metadata.repo;
+    let short_name = if repo_name.contains("github.com") {
+        repo_name.rsplit("github.com/").next().unwrap_or(repo_name)
+    } else {
+        repo_name.as_str()
+    };
+    let date_range = format!(
+        "{} \u{2013} {}",
+        data.metadata.date_range.start, data.metadata.date_range.end
+    );
+
+    render_title_card(
+        text_renderer,
+        width,
+        height,
+        &[(short_name.to_string(), 36.0, bright), (date_range, 18.0, dim)],
+    )
+}
+
+/// Outro card: closing totals for the whole history just animated. `Commit`
+/// carries no per-commit author (see `draw_commit_footer`), so "contributors"
+/// comes from the aggregate `Statistics.unique_authors` rather than a
+/// fabricated per-commit field; the line is omitted entirely when no
+/// statistics were supplied.
+fn render_outro_card(text_renderer: &TextRenderer, data: &CollectedData, width: u32, height: u32) -> Pixmap {
+    let bright = Color::from_rgba8(230, 230, 240, 255);
+    let dim = Color::from_rgba8(140, 140, 150, 255);
+
+    let mut lines = vec![
+        ("History complete".to_string(), 28.0, bright),
+        (
+            format!("{} commits across {} branches", data.commits.len(), data.branches.len()),
+            18.0,
+            dim,
+        ),
+    ];
+    if let Some(authors) = data.statistics.as_ref().map(|s| s.unique_authors) {
+        lines.push((format!("{authors} contributors"), 18.0, dim));
+    }
+
+    render_title_card(text_renderer, width, height, &lines)
+}
+
+/// Per-pixel linear cross-fade between two equally-sized raw RGBA buffers.
+/// `t` in `[0, 1]` weights `to` (0.0 = all `from`, 1.0 = all `to`).
+fn crossfade_frame(from: &[u8], to: &[u8], t: f32) -> Vec<u8> {
+    from.iter()
+        .zip(to.iter())
+        .map(|(&a, &b)| (f32::from(a) * (1.0 - t) + f32::from(b) * t).round() as u8)
+        .collect()
+}
+
 // ── Video rendering ─────────────────────────────────────────────────────────
 
+/// Resolve a `--codec` value to the ffmpeg encoder name for `-c:v`. Short
+/// family names are mapped to a sensible default encoder; anything else
+/// (including already-qualified ffmpeg encoder names like `libx264`) passes
+/// through unchanged, so existing `--codec libx264`-style invocations keep
+/// working.
+fn resolve_codec(codec: &str) -> &str {
+    match codec.to_ascii_lowercase().as_str() {
+        "h264" => "libx264",
+        "h265" | "hevc" => "libx265",
+        "vp9" => "libvpx-vp9",
+        "av1" => "libaom-av1",
+        "svt-av1" => "libsvtav1",
+        _ => codec,
+    }
+}
+
+/// The rawvideo-input/codec/pixfmt/preset args shared by every ffmpeg
+/// process `render_video` spawns (one per segment, plus the intro/outro
+/// cards). Keeping these in one place is what lets the segments be
+/// concatenated with `-c copy` afterwards: `-g`/`-force_key_frames` and the
+/// output path are appended by the caller, since those vary per segment.
+fn base_ffmpeg_args(config: &RenderConfig) -> Vec<String> {
+    let needs_format_filter = config.pix_fmt.starts_with("yuv");
+    let mut args = vec![
+        "-y".to_string(),
+        "-f".to_string(),
+        "rawvideo".to_string(),
+        "-pix_fmt".to_string(),
+        "rgba".to_string(),
+        "-s".to_string(),
+        format!("{}x{}", config.width, config.height),
+        "-r".to_string(),
+        config.fps.to_string(),
+        "-i".to_string(),
+        "-".to_string(),
+        "-c:v".to_string(),
+        resolve_codec(&config.codec).to_string(),
+        "-crf".to_string(),
+        config.crf.to_string(),
+        "-pix_fmt".to_string(),
+        config.pix_fmt.clone(),
+    ];
+    if needs_format_filter {
+        args.push("-vf".to_string());
+        args.push(format!("format={}", config.pix_fmt));
+    }
+    args.push("-preset".to_string());
+    args.push(config.preset.clone());
+    args
+}
+
+/// Spawn ffmpeg with `args` (already ending in the output path), hand it
+/// frames by calling `fill` with the write side of a bounded channel, then
+/// wait for it to exit and surface any failure (including the writer
+/// thread's, if ffmpeg's stdin closed early) with captured stderr attached.
+fn run_ffmpeg_segment(
+    args: Vec<String>,
+    fill: impl FnOnce(&mpsc::SyncSender<Vec<u8>>),
+    seg_path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut ffmpeg = Command::new("ffmpeg")
+        .args(&args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdin = ffmpeg.stdin.take().expect("Failed to open FFmpeg stdin");
+    let mut stderr = ffmpeg.stderr.take().expect("Failed to open FFmpeg stderr");
+    let stderr_thread = thread::spawn(move || {
+        let mut captured = String::new();
+        let _ = stderr.read_to_string(&mut captured);
+        captured
+    });
+
+    let (tx, rx) = mpsc::sync_channel::<Vec<u8>>(4);
+    let writer_thread = thread::spawn(move || -> std::io::Result<()> {
+        let mut stdin = stdin;
+        for frame_bytes in rx {
+            stdin.write_all(&frame_bytes)?;
+        }
+        Ok(())
+    });
+
+    fill(&tx);
+    drop(tx);
+
+    let writer_result = writer_thread.join().expect("Writer thread panicked");
+    let status = ffmpeg.wait()?;
+    let captured_stderr = stderr_thread.join().unwrap_or_default();
+
+    if !status.success() || writer_result.is_err() {
+        let mut msg = format!("FFmpeg exited with status: {} (segment {})", status, seg_path.display());
+        if let Err(e) = &writer_result {
+            msg.push_str(&format!(" (writer thread error: {})", e));
+        }
+        if !captured_stderr.trim().is_empty() {
+            msg.push_str(&format!("\n--- ffmpeg stderr ---\n{}", captured_stderr.trim()));
+        }
+        return Err(msg.into());
+    }
+    Ok(())
+}
+
+/// Encode a standalone segment file made only of frames `fill` sends in
+/// (used for the intro/outro cards, whose frames are pre-computed rather
+/// than coming from `render_frame`). `frame_count` sets `-g` so the whole
+/// card is one GOP.
+fn encode_segment(
+    config: &RenderConfig,
+    seg_path: &std::path::Path,
+    frame_count: u32,
+    fill: impl FnOnce(&mpsc::SyncSender<Vec<u8>>),
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = base_ffmpeg_args(config);
+    args.push("-g".to_string());
+    args.push(frame_count.max(1).to_string());
+    args.push("-force_key_frames".to_string());
+    args.push("expr:eq(n,0)".to_string());
+    args.push(seg_path.to_string_lossy().into_owned());
+    run_ffmpeg_segment(args, fill, seg_path)
+}
+
+/// Render animated commit frames `[start, end)` and encode them to their own
+/// segment file via their own ffmpeg process, so `render_video` can run
+/// several of these concurrently instead of funneling every frame through a
+/// single encoder. Forces a keyframe at the segment's first frame (`-g` sized
+/// to the whole segment) so each piece is safe to `-c copy` concat later.
+#[allow(clippy::too_many_arguments)]
+fn render_segment(
+    config: &RenderConfig,
+    data: &CollectedData,
+    layout: &NetworkLayout,
+    positioned_commits: &[PositionedCommit],
+    positioned_merges: &[PositionedMerge],
+    branch_labels: &[BranchLabel],
+    date_ticks: &[DateTick],
+    positioned_tags: &[PositionedTag],
+    branch_infos: &[BranchVisualInfo],
+    file_flows: &[FileFlow],
+    frame_stats: &[FrameStats],
+    num_commits: usize,
+    total_frames: u32,
+    start: u32,
+    end: u32,
+    seg_path: &std::path::Path,
+    cache_hits: &AtomicU32,
+    cache_misses: &AtomicU32,
+    frames_done: &AtomicU32,
+    timeline: Option<&Timeline>,
+    annotations: &[ResolvedAnnotation],
+    diagnostics: &Diagnostics,
+    debug_overlay: bool,
+    debug_overlay_threshold_secs: f32,
+) -> Result<Vec<RenderFrameMetrics>, Box<dyn std::error::Error>> {
+    let mut args = base_ffmpeg_args(config);
+    args.push("-g".to_string());
+    args.push((end - start).max(1).to_string());
+    args.push("-force_key_frames".to_string());
+    args.push("expr:eq(n,0)".to_string());
+    args.push(seg_path.to_string_lossy().into_owned());
+
+    let batch_size = rayon::current_num_threads().max(1) as u32;
+    let mut metrics: Vec<RenderFrameMetrics> = Vec::with_capacity((end - start) as usize);
+
+    run_ffmpeg_segment(
+        args,
+        |tx| {
+            let mut frame_idx = start;
+            while frame_idx < end {
+                let batch_end = (frame_idx + batch_size).min(end);
+                let indices: Vec<u32> = (frame_idx..batch_end).collect();
+
+                let frames: Vec<(Vec<u8>, RenderFrameMetrics)> = indices
+                    .par_iter()
+                    .map(|&idx| {
+                        let task_start = std::time::Instant::now();
+                        let progress = (idx + 1) as f32 / total_frames as f32;
+                        let visible_count =
+                            ((progress * num_commits as f32).ceil() as usize).min(num_commits);
+                        let tr = TextRenderer::new();
+
+                        let fs = if visible_count > 0 {
+                            frame_stats.get(visible_count - 1)
+                        } else {
+                            None
+                        };
+
+                        // The frame cache is keyed by `visible_count`, not `frame_index`
+                        // (the repo treats rendered pixels as a pure function of which
+                        // commits are visible). Annotation cards and the debug-overlay
+                        // HUD both break that: one depends on the literal frame index,
+                        // the other on this frame's own render time, so two frames
+                        // sharing a `visible_count` can need different overlay pixels.
+                        // Rather than widen the cache key (and invalidate every cache
+                        // entry from plainer runs), skip the cache entirely while
+                        // either is active.
+                        let cache_path = if annotations.is_empty() && !debug_overlay {
+                            config.cache_dir.as_ref().map(|dir| {
+                                let key = frame_cache_key(config, data, visible_count);
+                                dir.join(format!("{:016x}.rgba", key))
+                            })
+                        } else {
+                            None
+                        };
+
+                        let (rgba, metrics) =
+                            match cache_path.as_ref().and_then(|p| std::fs::read(p).ok()) {
+                                Some(bytes) => {
+                                    cache_hits.fetch_add(1, Ordering::Relaxed);
+                                    let metrics = compute_frame_metrics(
+                                        positioned_commits,
+                                        positioned_merges,
+                                        branch_labels,
+                                        positioned_tags,
+                                        visible_count,
+                                        idx,
+                                    );
+                                    (bytes, metrics)
+                                }
+                                None => {
+                                    if cache_path.is_some() {
+                                        cache_misses.fetch_add(1, Ordering::Relaxed);
+                                    }
+                                    let render_start = std::time::Instant::now();
+                                    let (pixmap, metrics) = render_frame(
+                                        layout,
+                                        positioned_commits,
+                                        positioned_merges,
+                                        branch_labels,
+                                        date_ticks,
+                                        positioned_tags,
+                                        branch_infos,
+                                        file_flows,
+                                        fs,
+                                        &tr,
+                                        data,
+                                        visible_count,
+                                        config.width,
+                                        config.height,
+                                        idx,
+                                        config.gradient_fills,
+                                        config.additive_glow,
+                                        &config.inspector_commit,
+                                        annotations,
+                                        debug_overlay
+                                            .then_some((diagnostics, debug_overlay_threshold_secs)),
+                                    );
+                                    diagnostics.record(FrameSample {
+                                        frame_index: idx,
+                                        render_secs: render_start.elapsed().as_secs_f64(),
+                                        commits_drawn: metrics.commits_drawn,
+                                        merge_markers_drawn: metrics.merge_markers_drawn,
+                                        labels_drawn: metrics.labels_drawn,
+                                        pixels_written: u64::from(config.width) * u64::from(config.height),
+                                    });
+                                    let bytes = pixmap.data().to_vec();
+                                    if let Some(ref path) = cache_path {
+                                        let _ = std::fs::write(path, &bytes);
+                                    }
+                                    (bytes, metrics)
+                                }
+                            };
+
+                        if let Some(tl) = timeline {
+                            tl.record_worker_task(
+                                "Video rendering",
+                                &format!("frame {idx}"),
+                                task_start,
+                                task_start.elapsed(),
+                            );
+                        }
+
+                        (rgba, metrics)
+                    })
+                    .collect();
+
+                for (rgba, m) in frames {
+                    metrics.push(m);
+                    let _ = tx.send(rgba);
+                }
+                frames_done.fetch_add(batch_end - frame_idx, Ordering::Relaxed);
+                frame_idx = batch_end;
+            }
+        },
+        seg_path,
+    )?;
+
+    Ok(metrics)
+}
+
+/// Render one frame (outside the batch/segment machinery) and return its raw
+/// RGBA bytes — used to grab the exact first/last animated frame so the
+/// intro/outro cards have something to cross-fade into and out of. Always
+/// renders without the `--debug-overlay` HUD: this is an extra one-off frame
+/// grab outside the normal per-frame diagnostics recording, so there's no
+/// meaningful frame-time history to show here.
+#[allow(clippy::too_many_arguments)]
+fn render_frame_bytes(
+    layout: &NetworkLayout,
+    positioned_commits: &[PositionedCommit],
+    positioned_merges: &[PositionedMerge],
+    branch_labels: &[BranchLabel],
+    date_ticks: &[DateTick],
+    positioned_tags: &[PositionedTag],
+    branch_infos: &[BranchVisualInfo],
+    file_flows: &[FileFlow],
+    frame_stats: &[FrameStats],
+    text_renderer: &TextRenderer,
+    data: &CollectedData,
+    config: &RenderConfig,
+    num_commits: usize,
+    total_frames: u32,
+    idx: u32,
+    annotations: &[ResolvedAnnotation],
+) -> Vec<u8> {
+    let progress = (idx + 1) as f32 / total_frames as f32;
+    let visible_count = ((progress * num_commits as f32).ceil() as usize).min(num_commits);
+    let fs = if visible_count > 0 { frame_stats.get(visible_count - 1) } else { None };
+    let (pixmap, _metrics) = render_frame(
+        layout,
+        positioned_commits,
+        positioned_merges,
+        branch_labels,
+        date_ticks,
+        positioned_tags,
+        branch_infos,
+        file_flows,
+        fs,
+        text_renderer,
+        data,
+        visible_count,
+        config.width,
+        config.height,
+        idx,
+        config.gradient_fills,
+        config.additive_glow,
+        &config.inspector_commit,
+        annotations,
+        None,
+    );
+    pixmap.data().to_vec()
+}
+
 pub fn render_video(
     data: &CollectedData,
     config: &RenderConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
+    timeline: Option<&Timeline>,
+) -> Result<(Vec<RenderFrameMetrics>, CacheStats), Box<dyn std::error::Error>> {
     let layout = NetworkLayout::from_data(data, config.width, config.height);
-    let (positioned_commits, branch_infos) = layout.position_commits_dynamic(data);
+    let commit_order = CommitOrder::from_str_or_default(&config.commit_order);
+    let (positioned_commits, branch_infos, file_flows) = layout.position_commits_dynamic(data, commit_order);
     let positioned_merges = layout.position_merges_dynamic(data, &positioned_commits);
     let branch_labels = layout.compute_branch_labels(&positioned_commits);
-    let date_ticks = layout.compute_date_ticks(data);
-    let positioned_tags = layout.position_tags(&positioned_commits);
+    let date_ticks = layout.compute_date_ticks(&positioned_commits);
+    let mut positioned_tags = layout.position_tags(&positioned_commits);
+    positioned_tags.extend(layout.position_release_labels(data, &positioned_commits));
     let text_renderer = TextRenderer::new();
 
     let num_commits = data.commits.len();
@@ -836,7 +2245,7 @@ pub fn render_video(
     }
 
     // Pre-compute frame stats for the overlay
-    let frame_stats = precompute_frame_stats(data, &layout.default_branch);
+    let frame_stats = precompute_frame_stats(data, &layout.default_branch, config.stale_by_generation);
 
     let duration_secs = config.duration_secs.unwrap_or_else(|| {
         ((num_commits as f32 / 10.0).ceil() as u32).max(5)
@@ -848,97 +2257,784 @@ pub fn render_video(
         num_commits, total_frames, duration_secs, config.fps
     );
 
+    let annotations: Vec<ResolvedAnnotation> = match &config.annotations {
+        Some(path) => match annotations::load_annotations(path) {
+            Ok(raw) => annotations::resolve_annotations(
+                &raw,
+                data,
+                num_commits,
+                total_frames,
+                config.fade_frames,
+                config.annotation_hold_secs,
+                config.fps,
+            ),
+            Err(e) => {
+                eprintln!("Warning: failed to load annotations from {}: {}", path.display(), e);
+                Vec::new()
+            }
+        },
+        None => Vec::new(),
+    };
+
+    let diagnostics = Diagnostics::new();
+    let debug_overlay_threshold_secs = config.debug_overlay_threshold_ms / 1000.0;
+
+    if config.output.to_str() == Some("-") && config.preview {
+        let metrics = render_sixel_preview(
+            data,
+            config,
+            &layout,
+            &positioned_commits,
+            &positioned_merges,
+            &branch_labels,
+            &date_ticks,
+            &positioned_tags,
+            &branch_infos,
+            &file_flows,
+            &frame_stats,
+            num_commits,
+            total_frames,
+            timeline,
+            &annotations,
+            &diagnostics,
+            config.debug_overlay,
+            debug_overlay_threshold_secs,
+        )?;
+        diagnostics.summary().print();
+        return Ok((metrics, CacheStats::default()));
+    }
+
+    if config.output.to_str() == Some("-") {
+        let metrics = render_to_stdout(
+            data,
+            config,
+            &layout,
+            &positioned_commits,
+            &positioned_merges,
+            &branch_labels,
+            &date_ticks,
+            &positioned_tags,
+            &branch_infos,
+            &file_flows,
+            &frame_stats,
+            num_commits,
+            total_frames,
+            timeline,
+            &annotations,
+            &diagnostics,
+            config.debug_overlay,
+            debug_overlay_threshold_secs,
+        )?;
+        diagnostics.summary().print();
+        return Ok((metrics, CacheStats::default()));
+    }
+
+    let is_gif = config
+        .output
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gif"));
+    if is_gif {
+        let metrics = render_gif(
+            data,
+            config,
+            &layout,
+            &positioned_commits,
+            &positioned_merges,
+            &branch_labels,
+            &date_ticks,
+            &positioned_tags,
+            &branch_infos,
+            &file_flows,
+            &frame_stats,
+            num_commits,
+            total_frames,
+            timeline,
+            &annotations,
+            &diagnostics,
+            config.debug_overlay,
+            debug_overlay_threshold_secs,
+        )?;
+        diagnostics.summary().print();
+        return Ok((metrics, CacheStats::default()));
+    }
+
+    if let Some(ref cache_dir) = config.cache_dir {
+        std::fs::create_dir_all(cache_dir)?;
+    }
+
     let output_path = config.output.to_str().unwrap_or("output.mp4");
 
-    let mut ffmpeg = Command::new("ffmpeg")
-        .args([
-            "-y",
-            "-f",
-            "rawvideo",
-            "-pix_fmt",
-            "rgba",
-            "-s",
-            &format!("{}x{}", config.width, config.height),
-            "-r",
-            &config.fps.to_string(),
-            "-i",
-            "-",
-            "-c:v",
-            "libx264",
-            "-pix_fmt",
-            "yuv420p",
-            "-preset",
-            "fast",
-            output_path,
-        ])
-        .stdin(Stdio::piped())
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn()?;
+    // Encode in parallel across roughly one ffmpeg process per rayon worker
+    // thread, each writing a standalone segment file, then stitch them back
+    // together with a final `-c copy` concat pass. Every segment (and the
+    // intro/outro cards below) is built from `base_ffmpeg_args`, so they all
+    // share identical codec/pixfmt/timebase parameters — the concat's hard
+    // requirement for stream-copy to work without re-encoding.
+    let num_segments = rayon::current_num_threads()
+        .min(total_frames.max(1) as usize)
+        .max(1);
+    let seg_len = (total_frames + num_segments as u32 - 1) / num_segments as u32;
+    let seg_bounds: Vec<(u32, u32)> = (0..num_segments as u32)
+        .map(|i| (i * seg_len, ((i + 1) * seg_len).min(total_frames)))
+        .filter(|&(s, e)| s < e)
+        .collect();
+
+    let tmp_dir = std::env::temp_dir();
+    let pid = std::process::id();
+    let seg_paths: Vec<std::path::PathBuf> = (0..seg_bounds.len())
+        .map(|i| tmp_dir.join(format!("commit-viz-seg-{pid}-{i}.mp4")))
+        .collect();
+
+    let cache_hits = AtomicU32::new(0);
+    let cache_misses = AtomicU32::new(0);
+    let frames_done = AtomicU32::new(0);
+
+    eprintln!(
+        "Encoding {} segment(s) across up to {} concurrent ffmpeg processes...",
+        seg_bounds.len(),
+        num_segments
+    );
+
+    // Intro/outro cards fade into/out of the first/last animated frame, so
+    // render those two frames up front (a small, one-off duplicate of work
+    // the segments below will also do) before the segments start.
+    let intro_secs = config.intro_secs.filter(|&s| s > 0.0);
+    let outro_secs = config.outro_secs.filter(|&s| s > 0.0);
+    let first_frame_bytes = intro_secs.map(|_| {
+        render_frame_bytes(
+            &layout, &positioned_commits, &positioned_merges, &branch_labels, &date_ticks,
+            &positioned_tags, &branch_infos, &file_flows, &frame_stats, &text_renderer, data, config,
+            num_commits, total_frames, 0, &annotations,
+        )
+    });
+    let last_frame_bytes = outro_secs.map(|_| {
+        render_frame_bytes(
+            &layout, &positioned_commits, &positioned_merges, &branch_labels, &date_ticks,
+            &positioned_tags, &branch_infos, &file_flows, &frame_stats, &text_renderer, data, config,
+            num_commits, total_frames, total_frames - 1, &annotations,
+        )
+    });
+
+    let mut pieces: Vec<std::path::PathBuf> = Vec::new();
+
+    if let (Some(secs), Some(first_bytes)) = (intro_secs, first_frame_bytes.as_ref()) {
+        let intro_card = render_intro_card(&text_renderer, data, config.width, config.height);
+        let intro_bytes = intro_card.data().to_vec();
+        let intro_frame_count = ((secs * config.fps as f32).round() as u32).max(1);
+        let fade_frames = config.fade_frames;
+        let intro_path = tmp_dir.join(format!("commit-viz-intro-{pid}.mp4"));
+        encode_segment(config, &intro_path, intro_frame_count + fade_frames, |tx| {
+            for _ in 0..intro_frame_count {
+                let _ = tx.send(intro_bytes.clone());
+            }
+            for i in 1..=fade_frames {
+                let t = i as f32 / (fade_frames + 1) as f32;
+                let _ = tx.send(crossfade_frame(&intro_bytes, first_bytes, t));
+            }
+        })?;
+        pieces.push(intro_path);
+    }
+
+    let seg_results: Vec<Result<Vec<RenderFrameMetrics>, Box<dyn std::error::Error>>> =
+        thread::scope(|scope| {
+            let handles: Vec<_> = seg_bounds
+                .iter()
+                .zip(seg_paths.iter())
+                .map(|(&(start, end), seg_path)| {
+                    scope.spawn(move || {
+                        render_segment(
+                            config,
+                            data,
+                            &layout,
+                            &positioned_commits,
+                            &positioned_merges,
+                            &branch_labels,
+                            &date_ticks,
+                            &positioned_tags,
+                            &branch_infos,
+                            &file_flows,
+                            &frame_stats,
+                            num_commits,
+                            total_frames,
+                            start,
+                            end,
+                            seg_path,
+                            &cache_hits,
+                            &cache_misses,
+                            &frames_done,
+                            timeline,
+                            &annotations,
+                            &diagnostics,
+                            config.debug_overlay,
+                            debug_overlay_threshold_secs,
+                        )
+                    })
+                })
+                .collect();
+
+            loop {
+                let done = frames_done.load(Ordering::Relaxed);
+                eprint!("\r  Frame {}/{}", done.min(total_frames), total_frames);
+                if handles.iter().all(|h| h.is_finished()) {
+                    break;
+                }
+                thread::sleep(std::time::Duration::from_millis(200));
+            }
+
+            handles.into_iter().map(|h| h.join().expect("Segment thread panicked")).collect()
+        });
+    eprintln!();
 
-    let stdin = ffmpeg.stdin.as_mut().expect("Failed to open FFmpeg stdin");
+    pieces.extend(seg_paths.iter().cloned());
+
+    if let (Some(secs), Some(last_bytes)) = (outro_secs, last_frame_bytes.as_ref()) {
+        let outro_card = render_outro_card(&text_renderer, data, config.width, config.height);
+        let outro_bytes = outro_card.data().to_vec();
+        let outro_frame_count = ((secs * config.fps as f32).round() as u32).max(1);
+        let fade_frames = config.fade_frames;
+        let outro_path = tmp_dir.join(format!("commit-viz-outro-{pid}.mp4"));
+        encode_segment(config, &outro_path, fade_frames + outro_frame_count, |tx| {
+            for i in 1..=fade_frames {
+                let t = i as f32 / (fade_frames + 1) as f32;
+                let _ = tx.send(crossfade_frame(last_bytes, &outro_bytes, t));
+            }
+            for _ in 0..outro_frame_count {
+                let _ = tx.send(outro_bytes.clone());
+            }
+        })?;
+        pieces.push(outro_path);
+    }
+
+    let mut all_metrics: Vec<RenderFrameMetrics> = Vec::with_capacity(total_frames as usize);
+    for result in seg_results {
+        all_metrics.extend(result?);
+    }
+
+    let list_path = tmp_dir.join(format!("commit-viz-concat-{pid}.txt"));
+    let mut list_contents = String::new();
+    for p in &pieces {
+        list_contents.push_str(&format!(
+            "file '{}'\n",
+            p.to_string_lossy().replace('\'', "'\\''")
+        ));
+    }
+    std::fs::write(&list_path, list_contents)?;
+
+    let concat_status = Command::new("ffmpeg")
+        .args(["-y", "-f", "concat", "-safe", "0", "-i"])
+        .arg(&list_path)
+        .args(["-c", "copy", output_path])
+        .status();
+
+    for p in &pieces {
+        let _ = std::fs::remove_file(p);
+    }
+    let _ = std::fs::remove_file(&list_path);
+
+    let concat_status = concat_status?;
+    if !concat_status.success() {
+        return Err(format!("ffmpeg concat exited with status: {}", concat_status).into());
+    }
+    eprintln!("Video written to {}", output_path);
+    diagnostics.summary().print();
+
+    let cache_stats = CacheStats {
+        hits: cache_hits.load(Ordering::Relaxed),
+        misses: cache_misses.load(Ordering::Relaxed),
+    };
+    Ok((all_metrics, cache_stats))
+}
+
+// ── Animated GIF output ──────────────────────────────────────────────────────
+
+/// Render the full frame sequence to an animated GIF: frames are rendered
+/// in parallel batches (same batching as `render_to_stdout`) and kept in memory
+/// so a single global palette can be quantized from sampled pixels across the
+/// whole animation, then each frame is Floyd-Steinberg dithered against it
+/// and written out through the hand-rolled LZW encoder.
+#[allow(clippy::too_many_arguments)]
+fn render_gif(
+    data: &CollectedData,
+    config: &RenderConfig,
+    layout: &NetworkLayout,
+    positioned_commits: &[PositionedCommit],
+    positioned_merges: &[PositionedMerge],
+    branch_labels: &[BranchLabel],
+    date_ticks: &[DateTick],
+    positioned_tags: &[PositionedTag],
+    branch_infos: &[BranchVisualInfo],
+    file_flows: &[FileFlow],
+    frame_stats: &[FrameStats],
+    num_commits: usize,
+    total_frames: u32,
+    timeline: Option<&Timeline>,
+    annotations: &[ResolvedAnnotation],
+    diagnostics: &Diagnostics,
+    debug_overlay: bool,
+    debug_overlay_threshold_secs: f32,
+) -> Result<Vec<RenderFrameMetrics>, Box<dyn std::error::Error>> {
+    let output_path = config.output.to_str().unwrap_or("output.gif");
+    eprintln!(
+        "Rendering GIF ({} colors, loop={})...",
+        config.gif_colors, config.gif_loop
+    );
 
-    // Render frames in parallel batches
     let batch_size = rayon::current_num_threads() * 2;
     let mut frame_idx = 0u32;
+    let mut frames: Vec<Vec<u8>> = Vec::with_capacity(total_frames as usize);
+    let mut samples: Vec<[u8; 3]> = Vec::new();
+    let mut all_metrics: Vec<RenderFrameMetrics> = Vec::with_capacity(total_frames as usize);
 
     while frame_idx < total_frames {
         let batch_end = (frame_idx + batch_size as u32).min(total_frames);
         let indices: Vec<u32> = (frame_idx..batch_end).collect();
 
-        let frames: Vec<Pixmap> = indices
+        let batch: Vec<(Vec<u8>, RenderFrameMetrics)> = indices
             .par_iter()
             .map(|&idx| {
+                let task_start = std::time::Instant::now();
                 let progress = (idx + 1) as f32 / total_frames as f32;
                 let visible_count =
                     ((progress * num_commits as f32).ceil() as usize).min(num_commits);
                 let tr = TextRenderer::new();
 
-                // Get the frame stats for this visible_count (1-indexed to 0-indexed)
                 let fs = if visible_count > 0 {
                     frame_stats.get(visible_count - 1)
                 } else {
                     None
                 };
 
-                render_frame(
-                    &layout,
-                    &positioned_commits,
-                    &positioned_merges,
-                    &branch_labels,
-                    &date_ticks,
-                    &positioned_tags,
-                    &branch_infos,
+                let render_start = std::time::Instant::now();
+                let (pixmap, metrics) = render_frame(
+                    layout,
+                    positioned_commits,
+                    positioned_merges,
+                    branch_labels,
+                    date_ticks,
+                    positioned_tags,
+                    branch_infos,
+                    file_flows,
                     fs,
                     &tr,
                     data,
                     visible_count,
                     config.width,
                     config.height,
-                )
+                    idx,
+                    config.gradient_fills,
+                    config.additive_glow,
+                    &config.inspector_commit,
+                    annotations,
+                    debug_overlay.then_some((diagnostics, debug_overlay_threshold_secs)),
+                );
+                diagnostics.record(FrameSample {
+                    frame_index: idx,
+                    render_secs: render_start.elapsed().as_secs_f64(),
+                    commits_drawn: metrics.commits_drawn,
+                    merge_markers_drawn: metrics.merge_markers_drawn,
+                    labels_drawn: metrics.labels_drawn,
+                    pixels_written: u64::from(config.width) * u64::from(config.height),
+                });
+
+                if let Some(tl) = timeline {
+                    tl.record_worker_task(
+                        "Video rendering",
+                        &format!("frame {idx}"),
+                        task_start,
+                        task_start.elapsed(),
+                    );
+                }
+
+                (pixmap.data().to_vec(), metrics)
             })
             .collect();
 
-        for pixmap in &frames {
-            stdin.write_all(pixmap.data())?;
+        for (rgba, metrics) in &batch {
+            // Sample every 17th pixel (odd stride avoids aliasing with the
+            // 4-byte RGBA stride) so palette-building stays cheap on large frames.
+            for px in rgba.chunks_exact(4).step_by(17) {
+                samples.push([px[0], px[1], px[2]]);
+            }
+            all_metrics.push(metrics.clone());
         }
+        frames.extend(batch.into_iter().map(|(rgba, _)| rgba));
 
         if frame_idx % config.fps == 0 || batch_end == total_frames {
             eprint!("\r  Frame {}/{}", batch_end, total_frames);
         }
+        frame_idx = batch_end;
+    }
+    eprintln!();
+
+    let mut palette = Palette::build(samples.iter().copied(), usize::from(config.gif_colors));
+    palette.refine_kmeans(&samples, 4);
+
+    let file = std::fs::File::create(output_path)?;
+    let writer = std::io::BufWriter::new(file);
+    let mut encoder = GifEncoder::new(
+        writer,
+        config.width as u16,
+        config.height as u16,
+        &palette,
+        Some(config.gif_loop),
+    )?;
+
+    let delay_cs = (100.0 / f64::from(config.fps)).round().max(1.0) as u16;
+    for rgba in &frames {
+        let indices = crate::gif::dither_frame(rgba, config.width, config.height, &palette);
+        encoder.write_frame(&indices, delay_cs)?;
+    }
+    encoder.finish()?;
+
+    eprintln!("GIF written to {}", output_path);
+    Ok(all_metrics)
+}
+
+// ── stdout Y4M streaming ─────────────────────────────────────────────────────
+
+/// Convert a tightly-packed RGBA8 buffer into planar YUV 4:2:0 (BT.601, full range).
+fn rgba_to_yuv420(rgba: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let w = width as usize;
+    let h = height as usize;
+    let mut y_plane = vec![0u8; w * h];
+    let mut u_plane = vec![0u8; (w / 2) * (h / 2)];
+    let mut v_plane = vec![0u8; (w / 2) * (h / 2)];
+
+    for row in 0..h {
+        for col in 0..w {
+            let idx = (row * w + col) * 4;
+            let r = f32::from(rgba[idx]);
+            let g = f32::from(rgba[idx + 1]);
+            let b = f32::from(rgba[idx + 2]);
+            let y = 0.299 * r + 0.587 * g + 0.114 * b;
+            y_plane[row * w + col] = y.round().clamp(0.0, 255.0) as u8;
+        }
+    }
+
+    // Chroma: average each 2x2 luma block before subsampling.
+    for crow in 0..h / 2 {
+        for ccol in 0..w / 2 {
+            let mut r_sum = 0.0f32;
+            let mut g_sum = 0.0f32;
+            let mut b_sum = 0.0f32;
+            for dy in 0..2 {
+                for dx in 0..2 {
+                    let idx = ((crow * 2 + dy) * w + (ccol * 2 + dx)) * 4;
+                    r_sum += f32::from(rgba[idx]);
+                    g_sum += f32::from(rgba[idx + 1]);
+                    b_sum += f32::from(rgba[idx + 2]);
+                }
+            }
+            let r = r_sum / 4.0;
+            let g = g_sum / 4.0;
+            let b = b_sum / 4.0;
+            let u = -0.168_736 * r - 0.331_264 * g + 0.5 * b + 128.0;
+            let v = 0.5 * r - 0.418_688 * g - 0.081_312 * b + 128.0;
+            let cidx = crow * (w / 2) + ccol;
+            u_plane[cidx] = u.round().clamp(0.0, 255.0) as u8;
+            v_plane[cidx] = v.round().clamp(0.0, 255.0) as u8;
+        }
+    }
+
+    let mut out = Vec::with_capacity(y_plane.len() + u_plane.len() + v_plane.len());
+    out.extend_from_slice(&y_plane);
+    out.extend_from_slice(&u_plane);
+    out.extend_from_slice(&v_plane);
+    out
+}
+
+/// Stream rendered frames as Y4M to stdout for piping into an external encoder
+/// (`--output -`), mirroring a vspipe-style workflow. Frames render out of
+/// order across the rayon pool, so a reorder buffer releases them to the
+/// writer strictly in sequence.
+fn render_to_stdout(
+    data: &CollectedData,
+    config: &RenderConfig,
+    layout: &NetworkLayout,
+    positioned_commits: &[PositionedCommit],
+    positioned_merges: &[PositionedMerge],
+    branch_labels: &[BranchLabel],
+    date_ticks: &[DateTick],
+    positioned_tags: &[PositionedTag],
+    branch_infos: &[BranchVisualInfo],
+    file_flows: &[FileFlow],
+    frame_stats: &[FrameStats],
+    num_commits: usize,
+    total_frames: u32,
+    timeline: Option<&Timeline>,
+    annotations: &[ResolvedAnnotation],
+    diagnostics: &Diagnostics,
+    debug_overlay: bool,
+    debug_overlay_threshold_secs: f32,
+) -> Result<Vec<RenderFrameMetrics>, Box<dyn std::error::Error>> {
+    let mut stdout = std::io::stdout().lock();
+    let header = format!(
+        "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C420\n",
+        config.width, config.height, config.fps
+    );
+    stdout.write_all(header.as_bytes())?;
+
+    let (tx, rx) = mpsc::sync_channel::<(u32, Vec<u8>)>(rayon::current_num_threads() * 2);
+    let progress = config.progress;
+    let writer_thread = thread::spawn(move || -> std::io::Result<()> {
+        let mut reorder: std::collections::BTreeMap<u32, Vec<u8>> = std::collections::BTreeMap::new();
+        let mut next_output = 0u32;
+        let start = std::time::Instant::now();
+        let mut last_report_time = start;
+        let mut last_report_frames = 0u32;
+
+        for (idx, yuv) in rx {
+            reorder.insert(idx, yuv);
+            while let Some(frame) = reorder.remove(&next_output) {
+                stdout.write_all(b"FRAME\n")?;
+                stdout.write_all(&frame)?;
+                next_output += 1;
+
+                if progress {
+                    let elapsed_since_report = last_report_time.elapsed().as_secs_f64();
+                    if elapsed_since_report >= 1.0 {
+                        let rolling_fps =
+                            (next_output - last_report_frames) as f64 / elapsed_since_report;
+                        eprintln!(
+                            "\r  {:.1}s elapsed, {}/{} frames, {:.1} fps",
+                            start.elapsed().as_secs_f64(),
+                            next_output,
+                            total_frames,
+                            rolling_fps
+                        );
+                        last_report_time = std::time::Instant::now();
+                        last_report_frames = next_output;
+                    }
+                }
+            }
+        }
+        Ok(())
+    });
+
+    let batch_size = rayon::current_num_threads() * 2;
+    let mut frame_idx = 0u32;
+    let mut all_metrics: Vec<RenderFrameMetrics> = Vec::with_capacity(total_frames as usize);
+
+    while frame_idx < total_frames {
+        let batch_end = (frame_idx + batch_size as u32).min(total_frames);
+        let indices: Vec<u32> = (frame_idx..batch_end).collect();
+
+        let frames: Vec<((u32, Vec<u8>), RenderFrameMetrics)> = indices
+            .par_iter()
+            .map(|&idx| {
+                let task_start = std::time::Instant::now();
+                let progress = (idx + 1) as f32 / total_frames as f32;
+                let visible_count =
+                    ((progress * num_commits as f32).ceil() as usize).min(num_commits);
+                let tr = TextRenderer::new();
+
+                let fs = if visible_count > 0 {
+                    frame_stats.get(visible_count - 1)
+                } else {
+                    None
+                };
+
+                let render_start = std::time::Instant::now();
+                let (pixmap, metrics) = render_frame(
+                    layout,
+                    positioned_commits,
+                    positioned_merges,
+                    branch_labels,
+                    date_ticks,
+                    positioned_tags,
+                    branch_infos,
+                    file_flows,
+                    fs,
+                    &tr,
+                    data,
+                    visible_count,
+                    config.width,
+                    config.height,
+                    idx,
+                    config.gradient_fills,
+                    config.additive_glow,
+                    &config.inspector_commit,
+                    annotations,
+                    debug_overlay.then_some((diagnostics, debug_overlay_threshold_secs)),
+                );
+                diagnostics.record(FrameSample {
+                    frame_index: idx,
+                    render_secs: render_start.elapsed().as_secs_f64(),
+                    commits_drawn: metrics.commits_drawn,
+                    merge_markers_drawn: metrics.merge_markers_drawn,
+                    labels_drawn: metrics.labels_drawn,
+                    pixels_written: u64::from(config.width) * u64::from(config.height),
+                });
+
+                if let Some(tl) = timeline {
+                    tl.record_worker_task(
+                        "Video rendering",
+                        &format!("frame {idx}"),
+                        task_start,
+                        task_start.elapsed(),
+                    );
+                }
+
+                (
+                    (idx, rgba_to_yuv420(pixmap.data(), config.width, config.height)),
+                    metrics,
+                )
+            })
+            .collect();
+
+        for (item, metrics) in frames {
+            all_metrics.push(metrics);
+            if tx.send(item).is_err() {
+                break;
+            }
+        }
 
         frame_idx = batch_end;
     }
 
-    drop(ffmpeg.stdin.take());
-    let status = ffmpeg.wait()?;
-    eprintln!();
+    drop(tx);
+    writer_thread.join().expect("Writer thread panicked")?;
 
-    if status.success() {
-        eprintln!("Video written to {}", output_path);
-    } else {
-        return Err(format!("FFmpeg exited with status: {}", status).into());
+    Ok(all_metrics)
+}
+
+/// Stream the animation directly to a sixel-capable terminal instead of
+/// spawning ffmpeg or writing a file: frames still render in parallel
+/// batches, same as `render_to_stdout`, but the writer thread sixel-encodes
+/// each one in display order, moves the cursor home so it draws over the
+/// previous frame, and paces output at `config.fps` so playback looks like a video
+/// rather than a scrolling dump. Selected by `--output -` plus `--preview`.
+#[allow(clippy::too_many_arguments)]
+fn render_sixel_preview(
+    data: &CollectedData,
+    config: &RenderConfig,
+    layout: &NetworkLayout,
+    positioned_commits: &[PositionedCommit],
+    positioned_merges: &[PositionedMerge],
+    branch_labels: &[BranchLabel],
+    date_ticks: &[DateTick],
+    positioned_tags: &[PositionedTag],
+    branch_infos: &[BranchVisualInfo],
+    file_flows: &[FileFlow],
+    frame_stats: &[FrameStats],
+    num_commits: usize,
+    total_frames: u32,
+    timeline: Option<&Timeline>,
+    annotations: &[ResolvedAnnotation],
+    diagnostics: &Diagnostics,
+    debug_overlay: bool,
+    debug_overlay_threshold_secs: f32,
+) -> Result<Vec<RenderFrameMetrics>, Box<dyn std::error::Error>> {
+    let width = config.width;
+    let height = config.height;
+    let frame_interval = std::time::Duration::from_secs_f64(1.0 / f64::from(config.fps.max(1)));
+
+    let (tx, rx) = mpsc::sync_channel::<(u32, Vec<u8>)>(rayon::current_num_threads() * 2);
+    let writer_thread = thread::spawn(move || -> std::io::Result<()> {
+        let mut stdout = std::io::stdout().lock();
+        let mut reorder: std::collections::BTreeMap<u32, Vec<u8>> = std::collections::BTreeMap::new();
+        let mut next_output = 0u32;
+        let mut next_deadline = std::time::Instant::now();
+
+        for (idx, rgba) in rx {
+            reorder.insert(idx, rgba);
+            while let Some(frame) = reorder.remove(&next_output) {
+                let now = std::time::Instant::now();
+                if now < next_deadline {
+                    thread::sleep(next_deadline - now);
+                }
+                let sixel = sixel::encode_frame(&frame, width, height);
+                stdout.write_all(b"\x1b[H")?;
+                stdout.write_all(&sixel)?;
+                stdout.flush()?;
+                next_deadline = std::time::Instant::now() + frame_interval;
+                next_output += 1;
+            }
+        }
+        Ok(())
+    });
+
+    let batch_size = rayon::current_num_threads() * 2;
+    let mut frame_idx = 0u32;
+    let mut all_metrics: Vec<RenderFrameMetrics> = Vec::with_capacity(total_frames as usize);
+
+    while frame_idx < total_frames {
+        let batch_end = (frame_idx + batch_size as u32).min(total_frames);
+        let indices: Vec<u32> = (frame_idx..batch_end).collect();
+
+        let frames: Vec<((u32, Vec<u8>), RenderFrameMetrics)> = indices
+            .par_iter()
+            .map(|&idx| {
+                let task_start = std::time::Instant::now();
+                let progress = (idx + 1) as f32 / total_frames as f32;
+                let visible_count =
+                    ((progress * num_commits as f32).ceil() as usize).min(num_commits);
+                let tr = TextRenderer::new();
+
+                let fs = if visible_count > 0 {
+                    frame_stats.get(visible_count - 1)
+                } else {
+                    None
+                };
+
+                let render_start = std::time::Instant::now();
+                let (pixmap, metrics) = render_frame(
+                    layout,
+                    positioned_commits,
+                    positioned_merges,
+                    branch_labels,
+                    date_ticks,
+                    positioned_tags,
+                    branch_infos,
+                    file_flows,
+                    fs,
+                    &tr,
+                    data,
+                    visible_count,
+                    config.width,
+                    config.height,
+                    idx,
+                    config.gradient_fills,
+                    config.additive_glow,
+                    &config.inspector_commit,
+                    annotations,
+                    debug_overlay.then_some((diagnostics, debug_overlay_threshold_secs)),
+                );
+                diagnostics.record(FrameSample {
+                    frame_index: idx,
+                    render_secs: render_start.elapsed().as_secs_f64(),
+                    commits_drawn: metrics.commits_drawn,
+                    merge_markers_drawn: metrics.merge_markers_drawn,
+                    labels_drawn: metrics.labels_drawn,
+                    pixels_written: u64::from(config.width) * u64::from(config.height),
+                });
+
+                if let Some(tl) = timeline {
+                    tl.record_worker_task(
+                        "Video rendering",
+                        &format!("frame {idx}"),
+                        task_start,
+                        task_start.elapsed(),
+                    );
+                }
+
+                ((idx, pixmap.data().to_vec()), metrics)
+            })
+            .collect();
+
+        for (item, metrics) in frames {
+            all_metrics.push(metrics);
+            if tx.send(item).is_err() {
+                break;
+            }
+        }
+
+        frame_idx = batch_end;
     }
 
-    Ok(())
+    drop(tx);
+    writer_thread.join().expect("Writer thread panicked")?;
+
+    Ok(all_metrics)
 }
@@ -1,7 +1,12 @@
+use crate::calendar_heatmap;
 use crate::data::CollectedData;
+use crate::stats::RenderComplexitySummary;
 use crate::text::TextRenderer;
+use crate::data::TimelineBucket;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::BTreeMap;
 use std::path::Path;
-use tiny_skia::{Color, Paint, PathBuilder, Pixmap, Transform};
+use tiny_skia::{Color, Paint, PathBuilder, Pixmap, Stroke, Transform};
 
 fn category_color(category: &str) -> Color {
     match category {
@@ -16,19 +21,358 @@ fn category_color(category: &str) -> Color {
     }
 }
 
+/// Okabe-Ito colorblind-safe category map: the same eight categories as
+/// `category_color`, but drawn from the Okabe-Ito palette (substituting the
+/// palette's pure black with a mid gray, since "other" needs to stay visible
+/// on both the dark and light themes).
+fn category_color_colorblind_safe(category: &str) -> Color {
+    match category {
+        "feature" => Color::from_rgba8(0, 114, 178, 255),
+        "bugfix" => Color::from_rgba8(213, 94, 0, 255),
+        "release" => Color::from_rgba8(240, 228, 66, 255),
+        "refactor" => Color::from_rgba8(204, 121, 167, 255),
+        "docs" => Color::from_rgba8(0, 158, 115, 255),
+        "ci" => Color::from_rgba8(86, 180, 233, 255),
+        "test" => Color::from_rgba8(230, 159, 0, 255),
+        _ => Color::from_rgba8(153, 153, 153, 255),
+    }
+}
+
+/// Color theme for `render_report`, selected via `--report-theme`. Covers
+/// the background, the three text tones the report draws everywhere
+/// (`white`/`light`/`dim` in the original hardcoded version), the
+/// per-category map, and the top-authors bar color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Theme {
+    Dark,
+    Light,
+    ColorblindSafe,
+}
+
+impl Theme {
+    /// Parse a `--report-theme` CLI value, falling back to Dark (the
+    /// report's original look) for anything unrecognized.
+    pub fn from_str_or_default(s: &str) -> Theme {
+        match s {
+            "light" => Theme::Light,
+            "colorblind_safe" | "colorblind-safe" => Theme::ColorblindSafe,
+            _ => Theme::Dark,
+        }
+    }
+
+    fn background(self) -> Color {
+        match self {
+            Theme::Light => Color::from_rgba8(246, 246, 248, 255),
+            Theme::Dark | Theme::ColorblindSafe => Color::from_rgba8(25, 25, 30, 255),
+        }
+    }
+
+    fn fg_primary(self) -> Color {
+        match self {
+            Theme::Light => Color::from_rgba8(20, 20, 24, 255),
+            Theme::Dark | Theme::ColorblindSafe => Color::from_rgba8(255, 255, 255, 255),
+        }
+    }
+
+    fn fg_secondary(self) -> Color {
+        match self {
+            Theme::Light => Color::from_rgba8(60, 60, 66, 255),
+            Theme::Dark | Theme::ColorblindSafe => Color::from_rgba8(200, 200, 200, 255),
+        }
+    }
+
+    fn fg_dim(self) -> Color {
+        match self {
+            Theme::Light => Color::from_rgba8(110, 110, 116, 255),
+            Theme::Dark | Theme::ColorblindSafe => Color::from_rgba8(140, 140, 140, 255),
+        }
+    }
+
+    fn category_color(self, category: &str) -> Color {
+        match self {
+            Theme::ColorblindSafe => category_color_colorblind_safe(category),
+            Theme::Dark | Theme::Light => category_color(category),
+        }
+    }
+
+    fn author_bar_color(self) -> Color {
+        match self {
+            Theme::Light => Color::from_rgba8(26, 115, 232, 200),
+            Theme::Dark | Theme::ColorblindSafe => Color::from_rgba8(66, 133, 244, 160),
+        }
+    }
+}
+
+/// Contribution heatmap panel for the report: same cell-per-day idea as
+/// `calendar_heatmap::render_calendar_heatmap`, but Monday-start (not
+/// Sunday-start), walked over `metadata.date_range` rather than the first/
+/// last commit seen, intensity bucketed relative to this report's own
+/// busiest day rather than GitHub's fixed absolute thresholds, and sized to
+/// fit inside the report canvas instead of its own standalone PNG.
+fn draw_contribution_heatmap(
+    pixmap: &mut Pixmap,
+    text: &TextRenderer,
+    theme: Theme,
+    data: &CollectedData,
+    stats_by_day: &BTreeMap<NaiveDate, u32>,
+    x: f32,
+    y: f32,
+    available_width: f32,
+) {
+    let white = theme.fg_primary();
+    let dim = theme.fg_dim();
+
+    text.draw_text(pixmap, "Contribution Calendar", x, y, 20.0, white);
+
+    let mut by_day = stats_by_day.clone();
+    if by_day.is_empty() {
+        for commit in &data.commits {
+            *by_day.entry(commit.timestamp.date_naive()).or_insert(0) += 1;
+        }
+    }
+
+    let (Some(&first_seen), Some(&last_seen)) = (by_day.keys().next(), by_day.keys().next_back())
+    else {
+        text.draw_text(pixmap, "No commits to chart", x, y + 35.0, 15.0, dim);
+        return;
+    };
+
+    let first = NaiveDate::parse_from_str(&data.metadata.date_range.start, "%Y-%m-%d")
+        .unwrap_or(first_seen);
+    let last =
+        NaiveDate::parse_from_str(&data.metadata.date_range.end, "%Y-%m-%d").unwrap_or(last_seen);
+
+    // Grid starts on the Monday on/before `first`; days before `first` in
+    // that leading week are left blank so the first real day lines up under
+    // its correct weekday row.
+    let grid_start = first - Duration::days(i64::from(first.weekday().num_days_from_monday()));
+    let num_weeks = ((last - grid_start).num_days() / 7 + 1).max(1);
+
+    let gap = 2.0f32;
+    let cell = (available_width / num_weeks as f32 - gap).clamp(3.0, 11.0);
+    let stride = cell + gap;
+    let grid_y = y + 24.0;
+
+    let max_count = by_day.values().copied().max().unwrap_or(1).max(1);
+    let ramp = match theme {
+        Theme::Light => [
+            Color::from_rgba8(225, 225, 228, 255),
+            Color::from_rgba8(172, 222, 186, 255),
+            Color::from_rgba8(110, 196, 134, 255),
+            Color::from_rgba8(58, 161, 97, 255),
+            Color::from_rgba8(27, 120, 67, 255),
+        ],
+        Theme::Dark | Theme::ColorblindSafe => [
+            Color::from_rgba8(45, 45, 52, 255),
+            Color::from_rgba8(46, 90, 60, 255),
+            Color::from_rgba8(50, 130, 75, 255),
+            Color::from_rgba8(55, 175, 90, 255),
+            Color::from_rgba8(70, 220, 105, 255),
+        ],
+    };
+
+    let mut last_month = None;
+    for week in 0..num_weeks {
+        let week_start = grid_start + Duration::days(week * 7);
+        let month = week_start.month();
+        if last_month != Some(month) {
+            last_month = Some(month);
+            let cx = x + week as f32 * stride;
+            text.draw_text(pixmap, calendar_heatmap::month_name(month), cx, grid_y - 6.0, 9.0, dim);
+        }
+    }
+
+    let mut d = first;
+    while d <= last {
+        let days_since_start = (d - grid_start).num_days();
+        let col = days_since_start / 7;
+        let row = i64::from(d.weekday().num_days_from_monday());
+        let px = x + col as f32 * stride;
+        let py = grid_y + row as f32 * stride;
+
+        let count = by_day.get(&d).copied().unwrap_or(0);
+        let level = if count == 0 {
+            0
+        } else {
+            1 + ((count as f32 / max_count as f32) * 3.0).round() as usize
+        }
+        .min(4);
+
+        let mut paint = Paint::default();
+        paint.set_color(ramp[level]);
+        calendar_heatmap::fill_rounded_rect(pixmap, px, py, cell, cell, 1.5, &paint);
+
+        d += Duration::days(1);
+    }
+}
+
+/// Color for an author's overlay line in the commits-over-time chart, cycled
+/// by rank rather than looked up by name since author names are arbitrary
+/// strings with no fixed identity the way category names have.
+fn author_color(rank: usize) -> Color {
+    const PALETTE: [(u8, u8, u8); 6] = [
+        (244, 180, 0),
+        (219, 68, 255),
+        (0, 200, 255),
+        (255, 99, 132),
+        (99, 255, 132),
+        (255, 159, 64),
+    ];
+    let (r, g, b) = PALETTE[rank % PALETTE.len()];
+    Color::from_rgba8(r, g, b, 220)
+}
+
+/// Commits-over-time line chart: `stats.commit_timeline` as a filled area,
+/// with the six busiest authors in `author_timelines` overlaid as thin
+/// polylines (the rest are not drawn), all
+/// sharing one x-axis (bucket index) and y-axis (commit count) scale.
+fn draw_commits_over_time(
+    pixmap: &mut Pixmap,
+    text: &TextRenderer,
+    theme: Theme,
+    commit_timeline: &[TimelineBucket],
+    author_timelines: &std::collections::HashMap<String, Vec<TimelineBucket>>,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+) {
+    let white = theme.fg_primary();
+    let dim = theme.fg_dim();
+
+    text.draw_text(pixmap, "Commits Over Time", x, y, 20.0, white);
+
+    if commit_timeline.is_empty() {
+        text.draw_text(pixmap, "No timeline data available", x, y + 35.0, 15.0, dim);
+        return;
+    }
+
+    let chart_x = x;
+    let chart_y = y + 20.0;
+    let chart_w = width;
+    let chart_h = height;
+
+    let max_count = commit_timeline.iter().map(|b| b.count).max().unwrap_or(1).max(1);
+    let n = commit_timeline.len();
+    let bucket_x = |i: usize| -> f32 {
+        if n <= 1 {
+            chart_x
+        } else {
+            chart_x + (i as f32 / (n - 1) as f32) * chart_w
+        }
+    };
+    let value_y = |count: u32| -> f32 { chart_y + chart_h - (count as f32 / max_count as f32) * chart_h };
+
+    // Gridlines + y-axis labels at 0%, 50%, 100% of the max bucket count.
+    let grid_stroke = Stroke { width: 1.0, ..Stroke::default() };
+    for frac in [0.0, 0.5, 1.0] {
+        let gy = chart_y + chart_h * (1.0 - frac);
+        let mut pb = PathBuilder::new();
+        pb.move_to(chart_x, gy);
+        pb.line_to(chart_x + chart_w, gy);
+        if let Some(path) = pb.finish() {
+            let mut paint = Paint::default();
+            paint.set_color(Color::from_rgba8(60, 60, 68, 255));
+            pixmap.stroke_path(&path, &paint, &grid_stroke, Transform::identity(), None);
+        }
+        let label = format!("{}", (max_count as f32 * frac).round() as u32);
+        text.draw_text(pixmap, &label, chart_x - 28.0, gy + 4.0, 10.0, dim);
+    }
+
+    // Area fill under the repo-wide total.
+    let mut area = PathBuilder::new();
+    area.move_to(bucket_x(0), chart_y + chart_h);
+    for (i, bucket) in commit_timeline.iter().enumerate() {
+        area.line_to(bucket_x(i), value_y(bucket.count));
+    }
+    area.line_to(bucket_x(n - 1), chart_y + chart_h);
+    area.close();
+    if let Some(path) = area.finish() {
+        let mut paint = Paint::default();
+        paint.set_color(Color::from_rgba8(66, 133, 244, 60));
+        pixmap.fill_path(&path, &paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+    }
+
+    let mut line = PathBuilder::new();
+    for (i, bucket) in commit_timeline.iter().enumerate() {
+        let (px, py) = (bucket_x(i), value_y(bucket.count));
+        if i == 0 {
+            line.move_to(px, py);
+        } else {
+            line.line_to(px, py);
+        }
+    }
+    if let Some(path) = line.finish() {
+        let mut paint = Paint::default();
+        paint.set_color(Color::from_rgba8(66, 133, 244, 255));
+        let stroke = Stroke { width: 2.0, ..Stroke::default() };
+        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+    }
+
+    // Per-author overlays, ranked by total commits so the busiest authors
+    // get the legend's first (and most memorable) colors.
+    let mut authors: Vec<(&String, &Vec<TimelineBucket>)> = author_timelines.iter().collect();
+    authors.sort_by_key(|(_, series)| std::cmp::Reverse(series.iter().map(|b| b.count).sum::<u32>()));
+
+    for (rank, (author, series)) in authors.iter().take(6).enumerate() {
+        if series.is_empty() {
+            continue;
+        }
+        let color = author_color(rank);
+        let mut pb = PathBuilder::new();
+        for (i, bucket) in series.iter().enumerate() {
+            let (px, py) = (bucket_x(i.min(n.saturating_sub(1))), value_y(bucket.count));
+            if i == 0 {
+                pb.move_to(px, py);
+            } else {
+                pb.line_to(px, py);
+            }
+        }
+        if let Some(path) = pb.finish() {
+            let mut paint = Paint::default();
+            paint.set_color(color);
+            let stroke = Stroke { width: 1.5, ..Stroke::default() };
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+
+        // Legend entry.
+        let legend_x = chart_x + chart_w + 14.0;
+        let legend_y = chart_y + rank as f32 * 16.0 + 10.0;
+        let mut paint = Paint::default();
+        paint.set_color(color);
+        let mut pb = PathBuilder::new();
+        pb.move_to(legend_x, legend_y - 4.0);
+        pb.line_to(legend_x + 12.0, legend_y - 4.0);
+        pb.line_to(legend_x + 12.0, legend_y);
+        pb.line_to(legend_x, legend_y);
+        pb.close();
+        if let Some(path) = pb.finish() {
+            pixmap.fill_path(&path, &paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+        }
+        let name = if author.chars().count() > 18 {
+            format!("{}...", author.chars().take(15).collect::<String>())
+        } else {
+            (*author).clone()
+        };
+        text.draw_text(pixmap, &name, legend_x + 16.0, legend_y, 11.0, dim);
+    }
+}
+
 pub fn render_report(
     data: &CollectedData,
     output_path: &Path,
+    render_complexity: Option<&RenderComplexitySummary>,
+    theme: Theme,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let width = 1920u32;
     let height = 1080u32;
     let mut pixmap = Pixmap::new(width, height).unwrap();
-    pixmap.fill(Color::from_rgba8(25, 25, 30, 255));
+    pixmap.fill(theme.background());
 
     let text = TextRenderer::new();
-    let white = Color::from_rgba8(255, 255, 255, 255);
-    let light = Color::from_rgba8(200, 200, 200, 255);
-    let dim = Color::from_rgba8(140, 140, 140, 255);
+    let white = theme.fg_primary();
+    let light = theme.fg_secondary();
+    let dim = theme.fg_dim();
 
     // Header
     let repo_name = &data.metadata.repo;
@@ -95,7 +439,7 @@ pub fn render_report(
         // Bar
         let bar_width = (count as f32 / max_count as f32) * (bar_area_right - bar_area_left);
         let mut paint = Paint::default();
-        paint.set_color(category_color(cat));
+        paint.set_color(theme.category_color(cat));
 
         let mut pb = PathBuilder::new();
         pb.move_to(bar_area_left, bar_y);
@@ -161,6 +505,51 @@ pub fn render_report(
         );
     }
 
+    // --- Render complexity ---
+    let rcx_y = 700.0;
+    text.draw_text(&mut pixmap, "Render Complexity Over Time", 40.0, rcx_y, 20.0, white);
+
+    match render_complexity {
+        Some(rc) => {
+            let lines = [
+                format!(
+                    "Commits drawn/frame: {}-{} (mean {:.1})",
+                    rc.min_commits_drawn, rc.max_commits_drawn, rc.mean_commits_drawn
+                ),
+                format!(
+                    "Branch lines drawn/frame: {}-{} (mean {:.1})",
+                    rc.min_branch_lines_drawn, rc.max_branch_lines_drawn, rc.mean_branch_lines_drawn
+                ),
+                format!("Peak merge markers in one frame: {}", rc.max_merge_markers_drawn),
+                format!("Peak text labels in one frame: {}", rc.max_labels_drawn),
+                format!(
+                    "Busiest frame: #{} ({} total draw calls)",
+                    rc.busiest_frame_index, rc.busiest_frame_total
+                ),
+            ];
+            for (i, line) in lines.iter().enumerate() {
+                text.draw_text(
+                    &mut pixmap,
+                    line,
+                    60.0,
+                    rcx_y + 35.0 + i as f32 * 26.0,
+                    15.0,
+                    light,
+                );
+            }
+        }
+        None => {
+            text.draw_text(
+                &mut pixmap,
+                "No video render pass has run yet — render complexity unavailable",
+                60.0,
+                rcx_y + 35.0,
+                15.0,
+                dim,
+            );
+        }
+    }
+
     // --- Top authors ---
     let auth_x = 1000.0;
     text.draw_text(&mut pixmap, "Top Authors", auth_x, 210.0, 20.0, white);
@@ -178,7 +567,7 @@ pub fn render_report(
         // Bar
         let bar_w = (author.commits as f32 / max_author_commits as f32) * 400.0;
         let mut paint = Paint::default();
-        paint.set_color(Color::from_rgba8(66, 133, 244, 160));
+        paint.set_color(theme.author_bar_color());
         let mut pb = PathBuilder::new();
         pb.move_to(auth_x, y);
         pb.line_to(auth_x + bar_w, y);
@@ -213,6 +602,22 @@ pub fn render_report(
         );
     }
 
+    // --- Contribution calendar heatmap ---
+    draw_contribution_heatmap(&mut pixmap, &text, theme, data, &stats.by_day, auth_x, 720.0, 880.0);
+
+    // --- Commits over time ---
+    draw_commits_over_time(
+        &mut pixmap,
+        &text,
+        theme,
+        &stats.commit_timeline,
+        &stats.author_timelines,
+        60.0,
+        920.0,
+        1700.0,
+        90.0,
+    );
+
     // Footer
     text.draw_text(
         &mut pixmap,
@@ -226,3 +631,142 @@ pub fn render_report(
     pixmap.save_png(output_path)?;
     Ok(())
 }
+
+/// Multi-repository comparison report: one category-distribution panel per
+/// `CollectedData`, tiled left to right, plus a trailing "All Repos
+/// Combined" aggregate panel. Every panel's bars share one global max count
+/// (computed across all panels, aggregate included) rather than each
+/// scaling against its own busiest category, so bar heights are directly
+/// comparable repo to repo.
+pub fn render_comparison_report(
+    datasets: &[&CollectedData],
+    output_path: &Path,
+    theme: Theme,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let width = 1920u32;
+    let height = 1080u32;
+    let mut pixmap = Pixmap::new(width, height).unwrap();
+    pixmap.fill(theme.background());
+
+    let text = TextRenderer::new();
+    let white = theme.fg_primary();
+    let dim = theme.fg_dim();
+
+    text.draw_text(&mut pixmap, "commit-viz Multi-Repository Comparison", 40.0, 50.0, 26.0, white);
+
+    if datasets.is_empty() {
+        text.draw_text(&mut pixmap, "No repositories to compare", 40.0, 100.0, 18.0, dim);
+        pixmap.save_png(output_path)?;
+        return Ok(());
+    }
+
+    let categories_ordered = [
+        "feature", "bugfix", "release", "refactor", "docs", "ci", "test", "other",
+    ];
+
+    // Shared legend, since the category->color mapping is the same in every panel.
+    let legend_y = 80.0;
+    for (i, cat) in categories_ordered.iter().enumerate() {
+        let lx = 40.0 + i as f32 * 160.0;
+        let mut paint = Paint::default();
+        paint.set_color(theme.category_color(cat));
+        let mut pb = PathBuilder::new();
+        pb.move_to(lx, legend_y - 10.0);
+        pb.line_to(lx + 12.0, legend_y - 10.0);
+        pb.line_to(lx + 12.0, legend_y);
+        pb.line_to(lx, legend_y);
+        pb.close();
+        if let Some(path) = pb.finish() {
+            pixmap.fill_path(&path, &paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+        }
+        text.draw_text(&mut pixmap, cat, lx + 16.0, legend_y, 12.0, dim);
+    }
+
+    // Per-repo category counts, plus the combined aggregate.
+    let mut panels: Vec<(String, std::collections::HashMap<String, u32>, u32, u32)> = Vec::new();
+    let mut combined_by_category: std::collections::HashMap<String, u32> = std::collections::HashMap::new();
+    let mut combined_total = 0u32;
+    for &data in datasets {
+        let stats = data.statistics.as_ref();
+        let by_category = stats.map(|s| s.by_category.clone()).unwrap_or_default();
+        let total = stats.map(|s| s.total_commits).unwrap_or(0);
+        let authors = stats.map(|s| s.unique_authors).unwrap_or(0);
+        for (cat, count) in &by_category {
+            *combined_by_category.entry(cat.clone()).or_insert(0) += count;
+        }
+        combined_total += total;
+        panels.push((data.metadata.repo.clone(), by_category, total, authors));
+    }
+    // Per-repo `unique_authors` counts aren't deduplicated across repos (the
+    // data model has no cross-repo author identity), so the combined panel
+    // reports commits only rather than a misleading summed author count.
+    panels.push(("All Repos Combined".to_string(), combined_by_category, combined_total, 0));
+
+    let global_max = panels
+        .iter()
+        .flat_map(|(_, by_cat, _, _)| categories_ordered.iter().filter_map(|c| by_cat.get(*c)))
+        .copied()
+        .max()
+        .unwrap_or(1)
+        .max(1);
+
+    let margin = 30.0f32;
+    let num_columns = panels.len();
+    let panel_width = ((width as f32 - margin * 2.0) / num_columns as f32).max(120.0);
+
+    let bar_area_top = 200.0;
+    let bar_area_bottom = 820.0;
+    let bar_w = 14.0f32;
+    let bar_gap = 4.0f32;
+
+    for (i, (name, by_cat, total, authors)) in panels.iter().enumerate() {
+        let panel_x = margin + i as f32 * panel_width;
+
+        let display_name = if name.chars().count() > 20 {
+            format!("{}...", name.chars().take(17).collect::<String>())
+        } else {
+            name.clone()
+        };
+        text.draw_text(&mut pixmap, &display_name, panel_x, 130.0, 16.0, white);
+        let summary = if *authors > 0 {
+            format!("{} commits | {} authors", total, authors)
+        } else {
+            format!("{} commits", total)
+        };
+        text.draw_text(&mut pixmap, &summary, panel_x, 155.0, 13.0, dim);
+
+        for (c, cat) in categories_ordered.iter().enumerate() {
+            let count = by_cat.get(*cat).copied().unwrap_or(0);
+            let bar_h = (count as f32 / global_max as f32) * (bar_area_bottom - bar_area_top);
+            let bx = panel_x + c as f32 * (bar_w + bar_gap);
+            let by = bar_area_bottom - bar_h;
+
+            let mut paint = Paint::default();
+            paint.set_color(theme.category_color(cat));
+            let mut pb = PathBuilder::new();
+            pb.move_to(bx, by);
+            pb.line_to(bx + bar_w, by);
+            pb.line_to(bx + bar_w, bar_area_bottom);
+            pb.line_to(bx, bar_area_bottom);
+            pb.close();
+            if let Some(path) = pb.finish() {
+                pixmap.fill_path(&path, &paint, tiny_skia::FillRule::Winding, Transform::identity(), None);
+            }
+        }
+
+        let mut paint = Paint::default();
+        paint.set_color(dim);
+        let stroke = Stroke { width: 1.0, ..Stroke::default() };
+        let mut pb = PathBuilder::new();
+        pb.move_to(panel_x, bar_area_bottom);
+        pb.line_to(panel_x + categories_ordered.len() as f32 * (bar_w + bar_gap), bar_area_bottom);
+        if let Some(path) = pb.finish() {
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+
+    text.draw_text(&mut pixmap, "Generated by commit-viz", 40.0, height as f32 - 30.0, 12.0, dim);
+
+    pixmap.save_png(output_path)?;
+    Ok(())
+}
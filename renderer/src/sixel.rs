@@ -0,0 +1,117 @@
+//! Minimal sixel graphics protocol encoder: quantizes each frame into
+//! 6-pixel-tall horizontal bands (sixel's native unit), builds a per-band
+//! color register palette with the same median-cut + k-means quantizer the
+//! GIF/indexed-PNG encoders use, and emits the DCS-wrapped byte sequence a
+//! sixel-capable terminal (xterm, mlterm, wezterm, ...) rasterizes directly —
+//! just enough to preview a frame in-terminal without an external crate.
+
+use crate::palette::Palette;
+
+/// Encode one RGBA frame (`tiny_skia::Pixmap::data()` layout: 4 bytes/pixel)
+/// as a complete sixel image, including the DCS introducer and ST terminator.
+pub fn encode_frame(rgba: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let width = width as usize;
+    let height = height as usize;
+    let mut out = Vec::new();
+
+    // DCS p1;p2;p3 q: p1=0 (aspect ratio 1:1), p2=1 (background pixels stay
+    // transparent rather than painted), p3=0 (unused, horizontal grid size).
+    out.extend_from_slice(b"\x1bP0;1;0q");
+    // Raster attributes: pan;pad 1:1, declared image size, so terminals can
+    // size their canvas before the first band of data arrives.
+    out.extend_from_slice(format!("\"1;1;{};{}", width, height).as_bytes());
+
+    let num_bands = height.div_ceil(6);
+    for band in 0..num_bands {
+        let row0 = band * 6;
+        let rows_in_band = (height - row0).min(6);
+
+        let mut band_pixels: Vec<[u8; 3]> = Vec::with_capacity(width * rows_in_band);
+        for row in 0..rows_in_band {
+            let y = row0 + row;
+            for x in 0..width {
+                let idx = (y * width + x) * 4;
+                band_pixels.push([rgba[idx], rgba[idx + 1], rgba[idx + 2]]);
+            }
+        }
+
+        let mut palette = Palette::build(band_pixels.iter().copied(), 256);
+        palette.refine_kmeans(&band_pixels, 2);
+
+        // Palette entry each column/row in this band quantizes to, `None`
+        // padding rows past `height` on a final, partial band.
+        let mut col_entries: Vec<[Option<usize>; 6]> = vec![[None; 6]; width];
+        for row in 0..rows_in_band {
+            let y = row0 + row;
+            for x in 0..width {
+                let idx = (y * width + x) * 4;
+                let color = [rgba[idx], rgba[idx + 1], rgba[idx + 2]];
+                col_entries[x][row] = Some(palette.nearest_index(color));
+            }
+        }
+
+        for (i, color) in palette.colors.iter().enumerate() {
+            out.extend_from_slice(format!("#{};2;{};{};{}", i, pct(color[0]), pct(color[1]), pct(color[2])).as_bytes());
+        }
+
+        for (i, _) in palette.colors.iter().enumerate() {
+            let masks: Vec<u8> = col_entries
+                .iter()
+                .map(|rows| {
+                    rows.iter().enumerate().fold(0u8, |m, (row, entry)| {
+                        if *entry == Some(i) {
+                            m | (1 << row)
+                        } else {
+                            m
+                        }
+                    })
+                })
+                .collect();
+
+            if masks.iter().all(|&m| m == 0) {
+                continue;
+            }
+
+            out.extend_from_slice(format!("#{}", i).as_bytes());
+            out.extend_from_slice(&rle_encode(&masks));
+            out.push(b'$');
+        }
+
+        if band + 1 < num_bands {
+            out.push(b'-');
+        }
+    }
+
+    out.extend_from_slice(b"\x1b\\");
+    out
+}
+
+/// Convert an 8-bit channel to the 0-100 percentage sixel color registers use.
+fn pct(v: u8) -> u32 {
+    (u32::from(v) * 100 + 127) / 255
+}
+
+/// Run-length encode a row of sixel mask bytes using the `!count char` repeat
+/// syntax for runs of 4 or more, falling back to literal repeats below that
+/// (not worth the 2-byte `!n` overhead for short runs).
+fn rle_encode(masks: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < masks.len() {
+        let m = masks[i];
+        let mut j = i + 1;
+        while j < masks.len() && masks[j] == m {
+            j += 1;
+        }
+        let run = j - i;
+        let ch = 63 + m;
+        if run >= 4 {
+            out.extend_from_slice(format!("!{}", run).as_bytes());
+            out.push(ch);
+        } else {
+            out.extend(std::iter::repeat(ch).take(run));
+        }
+        i = j;
+    }
+    out
+}
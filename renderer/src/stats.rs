@@ -1,5 +1,8 @@
+use crate::commit_graph::CommitGraph;
 use crate::data::CollectedData;
+use crate::describe::Describer;
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 
 /// Rolling "code inventory" metrics for the stats overlay, one per visible_count.
@@ -24,12 +27,19 @@ struct BranchState {
     files: u32,
     first_commit_time: DateTime<Utc>,
     last_commit_time: DateTime<Utc>,
+    first_commit_gen: u32,
     merged: bool,
 }
 
 /// Pre-compute one `FrameStats` for every `visible_count` from 1..=commits.len().
 /// This is called once before the render loop and indexed per-frame.
-pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str) -> Vec<FrameStats> {
+///
+/// `stale_by_generation`: classify a branch as stale by how many mainline
+/// commits it's behind (`gen(main_tip) - gen(branch_base) > 30`) instead of
+/// the wall-clock 30-day window — a long-lived branch that rebases or keeps
+/// committing stays "active" under the wall-clock rule even while it drifts
+/// further and further from main, which the commits-behind rule catches.
+pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str, stale_by_generation: bool) -> Vec<FrameStats> {
     let num_commits = data.commits.len();
     if num_commits == 0 {
         return Vec::new();
@@ -50,6 +60,22 @@ pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str) -> Vec
     // Track merges with timestamps for rolling 30-day throughput
     let mut merge_times: Vec<DateTime<Utc>> = Vec::new();
 
+    // Generation numbers turn "how far behind is this branch" into a graph
+    // distance instead of a wall-clock guess.
+    let graph = CommitGraph::build(data);
+    let mut main_tip_gen: u32 = 0;
+
+    // Real graph-distance-to-release, git-describe style, rather than a
+    // main-only commit count: only worth the per-commit ancestor walk when
+    // there's at least one tag to describe against.
+    let has_tags = data.commits.iter().any(|c| !c.tags.is_empty());
+    let describer = has_tags.then(|| Describer::new(data));
+    let tag_timestamp: HashMap<&str, DateTime<Utc>> = data
+        .commits
+        .iter()
+        .filter_map(|c| c.tags.first().map(|t| (t.as_str(), c.timestamp)))
+        .collect();
+
     let mut results = Vec::with_capacity(num_commits);
 
     for i in 0..num_commits {
@@ -57,6 +83,11 @@ pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str) -> Vec
         let branch = &commit.branch;
         let is_default = branch == default_branch;
         let now = commit.timestamp;
+        let gen = graph.generation(i);
+
+        if is_default {
+            main_tip_gen = main_tip_gen.max(gen);
+        }
 
         // Update branch state
         let state = branch_states
@@ -67,6 +98,7 @@ pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str) -> Vec
                 files: 0,
                 first_commit_time: now,
                 last_commit_time: now,
+                first_commit_gen: gen,
                 merged: is_default, // default branch is always "merged"
             });
 
@@ -117,14 +149,17 @@ pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str) -> Vec
 
             let age_days = (now - bs.first_commit_time).num_seconds() as f64 / 86400.0;
             let since_last = (now - bs.last_commit_time).num_seconds() as f64 / 86400.0;
+            let commits_behind = main_tip_gen.saturating_sub(bs.first_commit_gen);
 
-            // Integration debt = lines * age_days
-            integration_debt += (bs.lines as f64 * age_days) as u64;
+            // Integration debt = lines * commits mainline has moved on since
+            // this branch's base — how far behind, not how long it's sat.
+            integration_debt += bs.lines * u64::from(commits_behind);
 
-            if since_last <= 30.0 {
-                active_branches += 1;
-            } else {
+            let is_stale = if stale_by_generation { commits_behind > 30 } else { since_last > 30.0 };
+            if is_stale {
                 stale_branches += 1;
+            } else {
+                active_branches += 1;
             }
 
             if age_days > oldest_unmerged_days {
@@ -132,13 +167,25 @@ pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str) -> Vec
             }
         }
 
-        let days_since_release = match last_release_time {
+        let mut days_since_release = match last_release_time {
             Some(t) => (now - t).num_seconds() as f64 / 86400.0,
             None => {
-                // No release yet â€” days since first commit
+                // No release yet - days since first commit
                 (now - data.commits[0].timestamp).num_seconds() as f64 / 86400.0
             }
         };
+        let mut awaiting_release = main_commits_after_last_tag;
+
+        // Prefer the real graph distance to the nearest reachable tag over
+        // the main-only commit count above, which ignores branch topology.
+        if let Some(ref describer) = describer {
+            if let Some(d) = describer.describe(i) {
+                awaiting_release = d.depth;
+                if let Some(&release_time) = tag_timestamp.get(d.name.as_str()) {
+                    days_since_release = (now - release_time).num_seconds() as f64 / 86400.0;
+                }
+            }
+        }
 
         // Merge throughput: merges in the last 30 days of the visible window
         let cutoff = now - chrono::Duration::days(30);
@@ -152,7 +199,7 @@ pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str) -> Vec
             unmerged_files,
             integration_debt,
             days_since_release,
-            awaiting_release: main_commits_after_last_tag,
+            awaiting_release,
             oldest_unmerged_days,
             merge_throughput,
         });
@@ -160,3 +207,74 @@ pub fn precompute_frame_stats(data: &CollectedData, default_branch: &str) -> Vec
 
     results
 }
+
+/// Draw-call counts for a single rendered frame, recorded by `render::render_video`
+/// so the pipeline can spot frames where the network layout gets overcrowded.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RenderFrameMetrics {
+    pub frame_index: u32,
+    pub commits_drawn: u32,
+    pub branch_lines_drawn: u32,
+    pub merge_markers_drawn: u32,
+    pub labels_drawn: u32,
+    pub bounds_width: f32,
+    pub bounds_height: f32,
+}
+
+/// Aggregated render-complexity summary (min/max/mean plus the busiest frame)
+/// computed across an entire render's `RenderFrameMetrics`.
+#[derive(Clone, Debug, Default)]
+pub struct RenderComplexitySummary {
+    pub min_commits_drawn: u32,
+    pub max_commits_drawn: u32,
+    pub mean_commits_drawn: f64,
+    pub min_branch_lines_drawn: u32,
+    pub max_branch_lines_drawn: u32,
+    pub mean_branch_lines_drawn: f64,
+    pub max_merge_markers_drawn: u32,
+    pub max_labels_drawn: u32,
+    pub busiest_frame_index: u32,
+    pub busiest_frame_total: u32,
+}
+
+/// Aggregate per-frame render metrics into a summary. The "busiest" frame is
+/// the one with the most total draw calls (commits + branch lines + merge
+/// markers + labels).
+pub fn summarize_render_complexity(frames: &[RenderFrameMetrics]) -> Option<RenderComplexitySummary> {
+    if frames.is_empty() {
+        return None;
+    }
+
+    let mut summary = RenderComplexitySummary {
+        min_commits_drawn: u32::MAX,
+        min_branch_lines_drawn: u32::MAX,
+        ..Default::default()
+    };
+
+    let mut commits_sum: u64 = 0;
+    let mut lines_sum: u64 = 0;
+
+    for f in frames {
+        summary.min_commits_drawn = summary.min_commits_drawn.min(f.commits_drawn);
+        summary.max_commits_drawn = summary.max_commits_drawn.max(f.commits_drawn);
+        summary.min_branch_lines_drawn = summary.min_branch_lines_drawn.min(f.branch_lines_drawn);
+        summary.max_branch_lines_drawn = summary.max_branch_lines_drawn.max(f.branch_lines_drawn);
+        summary.max_merge_markers_drawn = summary.max_merge_markers_drawn.max(f.merge_markers_drawn);
+        summary.max_labels_drawn = summary.max_labels_drawn.max(f.labels_drawn);
+
+        commits_sum += u64::from(f.commits_drawn);
+        lines_sum += u64::from(f.branch_lines_drawn);
+
+        let total = f.commits_drawn + f.branch_lines_drawn + f.merge_markers_drawn + f.labels_drawn;
+        if total > summary.busiest_frame_total {
+            summary.busiest_frame_total = total;
+            summary.busiest_frame_index = f.frame_index;
+        }
+    }
+
+    let n = frames.len() as f64;
+    summary.mean_commits_drawn = commits_sum as f64 / n;
+    summary.mean_branch_lines_drawn = lines_sum as f64 / n;
+
+    Some(summary)
+}
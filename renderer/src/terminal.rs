@@ -0,0 +1,211 @@
+use crate::change_flow_charts::{self, ChangeFlowMetrics, ColorScheme, DateWindow};
+
+/// Unicode shade blocks, light to dark, used to approximate a color's
+/// intensity when a chart is flattened down to a single character per cell.
+const BLOCKS: [char; 4] = ['░', '▒', '▓', '█'];
+
+/// Renders a handful of change flow charts straight to a color terminal
+/// instead of a PNG file, for quick previews in CI logs or over SSH where
+/// there's no way to open an image: the release heatmap, velocity/drought,
+/// release cadence, and work disposition charts. The rest stay PNG-only via
+/// `change_flow_charts::render_all`.
+pub struct TerminalRenderer {
+    width: usize,
+}
+
+impl TerminalRenderer {
+    pub fn new() -> TerminalRenderer {
+        let width = std::env::var("COLUMNS")
+            .ok()
+            .and_then(|s| s.parse::<usize>().ok())
+            .unwrap_or(120)
+            .clamp(40, 240);
+        TerminalRenderer { width }
+    }
+
+    fn ansi_fg(color: tiny_skia::Color) -> String {
+        format!(
+            "\x1b[38;2;{};{};{}m",
+            (color.red() * 255.0).round() as u8,
+            (color.green() * 255.0).round() as u8,
+            (color.blue() * 255.0).round() as u8,
+        )
+    }
+
+    const RESET: &'static str = "\x1b[0m";
+
+    /// Terminal analogue of chart 1: the calendar heatmap flattened into a
+    /// single row of blocks, one per bucket of days, quantized to fit the
+    /// detected terminal width and colored via `heat_color`/`magenta`
+    /// exactly like the PNG version.
+    pub fn render_release_heatmap(&self, wm: &ChangeFlowMetrics, window: DateWindow, scheme: ColorScheme) {
+        println!("Commit-to-Release Latency Heatmap (terminal preview)");
+
+        let entries: Vec<_> = wm.commit_to_release_days.iter().filter(|e| window.contains(&e.date)).collect();
+        if entries.is_empty() {
+            println!("  no data");
+            return;
+        }
+
+        let released: Vec<f64> = entries.iter()
+            .filter(|e| e.avg_days_to_release >= 0.0)
+            .map(|e| e.avg_days_to_release)
+            .collect();
+        let median = change_flow_charts::median_f64(&released);
+        let pct_7d = change_flow_charts::pct_within(&released, 7.0);
+        println!("  median {:.1}d | within 7d {:.0}%", median, pct_7d);
+
+        let cols = self.width.saturating_sub(2).max(10);
+        let per_cell = entries.len().div_ceil(cols);
+
+        let mut line = String::new();
+        for chunk in entries.chunks(per_cell.max(1)) {
+            let any_unreleased = chunk.iter().any(|e| e.avg_days_to_release < 0.0);
+            let released_in_chunk: Vec<f64> = chunk.iter()
+                .filter(|e| e.avg_days_to_release >= 0.0)
+                .map(|e| e.avg_days_to_release)
+                .collect();
+
+            let color = if any_unreleased {
+                change_flow_charts::magenta(scheme)
+            } else {
+                let avg = released_in_chunk.iter().sum::<f64>() / released_in_chunk.len().max(1) as f64;
+                let t = (avg as f32 / 30.0).clamp(0.0, 1.0);
+                change_flow_charts::heat_color(scheme, t)
+            };
+            let level = if any_unreleased {
+                BLOCKS.len() - 1
+            } else {
+                let avg = released_in_chunk.iter().sum::<f64>() / released_in_chunk.len().max(1) as f64;
+                (((avg / 30.0).clamp(0.0, 1.0)) * (BLOCKS.len() - 1) as f64).round() as usize
+            };
+
+            line.push_str(&Self::ansi_fg(color));
+            line.push(BLOCKS[level.min(BLOCKS.len() - 1)]);
+        }
+        line.push_str(Self::RESET);
+        println!("  {}", line);
+    }
+
+    /// Terminal analogue of chart 3: daily commit counts bucketed to the
+    /// terminal width, colored by each bucket's dominant category, with
+    /// drought buckets (zero commits) rendered as a dim magenta gap.
+    pub fn render_velocity_drought(&self, wm: &ChangeFlowMetrics, window: DateWindow, scheme: ColorScheme) {
+        println!("Commit Velocity & Drought Periods (terminal preview)");
+
+        let velocity: Vec<_> = wm.daily_velocity.iter().filter(|v| window.contains(&v.date)).collect();
+        if velocity.is_empty() {
+            println!("  no data");
+            return;
+        }
+
+        let droughts: Vec<_> = wm.drought_periods.iter()
+            .filter(|d| window.contains(&d.start_date) || window.contains(&d.end_date))
+            .collect();
+        let total_drought_days: u32 = droughts.iter().map(|d| d.duration_days).sum();
+        println!("  droughts (7+ days): {} | total drought days: {}", droughts.len(), total_drought_days);
+
+        let cols = self.width.saturating_sub(2).max(10);
+        let per_cell = velocity.len().div_ceil(cols).max(1);
+        let max_count = velocity.iter().map(|v| v.count).max().unwrap_or(1).max(1);
+
+        let mut line = String::new();
+        for chunk in velocity.chunks(per_cell) {
+            let avg_count = chunk.iter().map(|v| v.count as f64).sum::<f64>() / chunk.len() as f64;
+            if avg_count == 0.0 {
+                line.push_str(&Self::ansi_fg(change_flow_charts::magenta(scheme)));
+                line.push('·');
+                continue;
+            }
+            let dominant = &chunk[chunk.len() / 2].dominant_category;
+            let color = change_flow_charts::category_color(scheme, dominant);
+            let level = ((avg_count / max_count as f64) * (BLOCKS.len() - 1) as f64).round() as usize;
+            line.push_str(&Self::ansi_fg(color));
+            line.push(BLOCKS[level.min(BLOCKS.len() - 1)]);
+        }
+        line.push_str(Self::RESET);
+        println!("  {}", line);
+    }
+
+    /// Terminal analogue of chart 5: release intervals flattened into a
+    /// single spark row, one block per bucket, colored by distance from the
+    /// mean exactly like the lollipop dots in the PNG version (green within
+    /// 1 stdev, yellow within 2, red beyond), with the healthy band printed
+    /// out as a plain-text range underneath.
+    pub fn render_release_cadence(&self, wm: &ChangeFlowMetrics, scheme: ColorScheme) {
+        println!("Release Cadence & Interval Distribution (terminal preview)");
+
+        let intervals = &wm.release_intervals;
+        if intervals.is_empty() {
+            println!("  not enough releases for analysis");
+            return;
+        }
+
+        println!(
+            "  mean {:.1}d | median {:.1}d | cv {:.2} | longest gap {:.1}d",
+            wm.release_interval_mean, wm.release_interval_median,
+            wm.release_interval_cv, wm.release_interval_longest_gap
+        );
+
+        let mean = wm.release_interval_mean;
+        let stdev = if wm.release_interval_cv > 0.0 { mean * wm.release_interval_cv } else { mean * 0.3 };
+        let max_days = intervals.iter().map(|r| r.days_since_previous).fold(0.0f64, f64::max).max(1.0);
+
+        let cols = self.width.saturating_sub(2).max(10);
+        let per_cell = intervals.len().div_ceil(cols).max(1);
+
+        let mut line = String::new();
+        for chunk in intervals.chunks(per_cell) {
+            let avg = chunk.iter().map(|r| r.days_since_previous).sum::<f64>() / chunk.len() as f64;
+            let dist = (avg - mean).abs();
+            let color = if dist < stdev {
+                change_flow_charts::heat_color(scheme, 0.0)
+            } else if dist < stdev * 2.0 {
+                change_flow_charts::heat_color(scheme, 0.5)
+            } else {
+                change_flow_charts::heat_color(scheme, 1.0)
+            };
+            let level = ((avg / max_days).clamp(0.0, 1.0) * (BLOCKS.len() - 1) as f64).round() as usize;
+            line.push_str(&Self::ansi_fg(color));
+            line.push(BLOCKS[level.min(BLOCKS.len() - 1)]);
+        }
+        line.push_str(Self::RESET);
+        println!("  {}", line);
+        println!("  healthy band: {:.1}d - {:.1}d (green)", (mean - stdev).max(0.0), mean + stdev);
+    }
+
+    /// Terminal analogue of chart 6: the fast/slow/unmerged lines-of-change
+    /// proportions as labeled pipe-gauge bars, same green/yellow/red coloring
+    /// as the donut's inner ring.
+    pub fn render_work_disposition(&self, wm: &ChangeFlowMetrics, scheme: ColorScheme) {
+        println!("Work Disposition (terminal preview)");
+
+        let wd = &wm.work_disposition;
+        let total = wd.fast_merged_lines + wd.slow_merged_lines + wd.unmerged_lines;
+        if total == 0 {
+            println!("  no disposition data");
+            return;
+        }
+        let total_f = total as f64;
+
+        let bar_width = self.width.saturating_sub(32).max(10);
+        let segments = [
+            ("Fast merged (<7d)", wd.fast_merged_lines, change_flow_charts::heat_color(scheme, 0.0)),
+            ("Slow merged (>7d)", wd.slow_merged_lines, change_flow_charts::heat_color(scheme, 0.5)),
+            ("Unmerged", wd.unmerged_lines, change_flow_charts::heat_color(scheme, 1.0)),
+        ];
+
+        for (label, lines, color) in segments {
+            let pct = lines as f64 / total_f * 100.0;
+            let filled = ((pct / 100.0) * bar_width as f64).round() as usize;
+
+            let mut bar = String::new();
+            bar.push_str(&Self::ansi_fg(color));
+            bar.push_str(&"█".repeat(filled));
+            bar.push_str(Self::RESET);
+            bar.push_str(&"░".repeat(bar_width.saturating_sub(filled)));
+
+            println!("  {:<20} |{}| {:5.1}%", label, bar, pct);
+        }
+    }
+}
@@ -3,6 +3,27 @@ use tiny_skia::{Color, Pixmap};
 
 static FONT_DATA: &[u8] = include_bytes!("../assets/Inconsolata-Regular.ttf");
 
+/// Horizontal alignment of drawn text relative to the given `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    /// `x` is the left edge of the text (the existing `draw_text` behavior).
+    Start,
+    /// `x` is the horizontal center of the text.
+    Middle,
+    /// `x` is the right edge of the text.
+    End,
+}
+
+/// Vertical alignment of drawn text relative to the given `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerticalAnchor {
+    /// `y` is the text baseline (the existing `draw_text` behavior).
+    Baseline,
+    /// `y` is the top of the glyphs' ascent, rather than the baseline —
+    /// avoids callers having to guess an offset to align to a box's top edge.
+    Top,
+}
+
 pub struct TextRenderer {
     font: Font,
 }
@@ -22,6 +43,92 @@ impl TextRenderer {
         y: f32,
         size: f32,
         color: Color,
+    ) {
+        self.draw_line(pixmap, text, x, y, size, color);
+    }
+
+    /// Like `draw_text`, but with horizontal/vertical anchoring and optional
+    /// word-wrap. `h_anchor`/`v_anchor` position the text relative to `(x,
+    /// y)` instead of always treating it as the left baseline; `max_width`,
+    /// when set, wraps on whitespace into multiple lines spaced by the
+    /// font's own line height (`new_line_size`: ascent - descent + line gap)
+    /// at `size`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_ex(
+        &self,
+        pixmap: &mut Pixmap,
+        text: &str,
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Color,
+        h_anchor: Anchor,
+        v_anchor: VerticalAnchor,
+        max_width: Option<f32>,
+    ) {
+        let lines = match max_width {
+            Some(w) => self.wrap_text(text, size, w),
+            None => vec![text.to_string()],
+        };
+
+        let metrics = self.font.horizontal_line_metrics(size).unwrap_or(fontdue::LineMetrics {
+            ascent: size * 0.8,
+            descent: -size * 0.2,
+            line_gap: 0.0,
+            new_line_size: size * 1.2,
+        });
+
+        let first_baseline = match v_anchor {
+            VerticalAnchor::Baseline => y,
+            VerticalAnchor::Top => y + metrics.ascent,
+        };
+
+        for (i, line) in lines.iter().enumerate() {
+            let line_y = first_baseline + i as f32 * metrics.new_line_size;
+            let line_x = match h_anchor {
+                Anchor::Start => x,
+                Anchor::Middle => x - self.measure_text(line, size) / 2.0,
+                Anchor::End => x - self.measure_text(line, size),
+            };
+            self.draw_line(pixmap, line, line_x, line_y, size, color);
+        }
+    }
+
+    /// Break `text` on whitespace into lines no wider than `max_width` (at
+    /// `size`), greedily packing as many words per line as fit. A single
+    /// word wider than `max_width` is kept whole on its own line rather than
+    /// being split mid-word.
+    fn wrap_text(&self, text: &str, size: f32, max_width: f32) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut current = String::new();
+
+        for word in text.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{current} {word}")
+            };
+            if !current.is_empty() && self.measure_text(&candidate, size) > max_width {
+                lines.push(std::mem::take(&mut current));
+                current = word.to_string();
+            } else {
+                current = candidate;
+            }
+        }
+        if !current.is_empty() || lines.is_empty() {
+            lines.push(current);
+        }
+        lines
+    }
+
+    fn draw_line(
+        &self,
+        pixmap: &mut Pixmap,
+        text: &str,
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Color,
     ) {
         let r = color.red();
         let g = color.green();
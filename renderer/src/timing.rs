@@ -0,0 +1,161 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A single timed unit of work: a whole phase, or one worker task within a
+/// parallel phase (change-flow charts, per-frame rendering).
+pub struct Span {
+    pub phase: String,
+    pub name: String,
+    pub start_offset_secs: f64,
+    pub duration_secs: f64,
+    pub worker: Option<String>,
+}
+
+/// Collects timed spans across the render pipeline so they can be rendered
+/// as an HTML Gantt report. Cheap to share across rayon worker threads: all
+/// mutation goes through a single `Mutex<Vec<Span>>`.
+pub struct Timeline {
+    total_start: Instant,
+    spans: Mutex<Vec<Span>>,
+}
+
+impl Timeline {
+    pub fn new(total_start: Instant) -> Self {
+        Timeline {
+            total_start,
+            spans: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Record a span that already ran, given its start instant and duration.
+    /// `worker` identifies which rayon thread handled it, for parallel phases.
+    pub fn record(&self, phase: &str, name: &str, start: Instant, duration: Duration, worker: Option<String>) {
+        let span = Span {
+            phase: phase.to_string(),
+            name: name.to_string(),
+            start_offset_secs: (start - self.total_start).as_secs_f64(),
+            duration_secs: duration.as_secs_f64(),
+            worker,
+        };
+        self.spans.lock().unwrap().push(span);
+    }
+
+    /// Run `f`, timing it as a single span under `phase`.
+    pub fn timed<F, R>(&self, phase: &str, name: &str, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let start = Instant::now();
+        let result = f();
+        self.record(phase, name, start, start.elapsed(), None);
+        result
+    }
+
+    /// Record a span for one rayon worker task, tagging it with the current
+    /// rayon thread index so the report shows how work spread across threads.
+    pub fn record_worker_task(&self, phase: &str, name: &str, start: Instant, duration: Duration) {
+        let worker = rayon::current_thread_index().map(|i| format!("worker-{i}"));
+        self.record(phase, name, start, duration, worker);
+    }
+
+    pub fn write_html(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let spans = self.spans.lock().unwrap();
+        let html = render_html(&spans);
+        fs::write(path, html)?;
+        Ok(())
+    }
+}
+
+fn render_html(spans: &[Span]) -> String {
+    let total_elapsed = spans
+        .iter()
+        .map(|s| s.start_offset_secs + s.duration_secs)
+        .fold(0.0f64, f64::max)
+        .max(0.001);
+
+    // Group spans by phase, preserving first-seen order.
+    let mut phase_order: Vec<String> = Vec::new();
+    for s in spans {
+        if !phase_order.contains(&s.phase) {
+            phase_order.push(s.phase.clone());
+        }
+    }
+
+    let mut phase_total: std::collections::HashMap<&str, f64> = std::collections::HashMap::new();
+    for s in spans {
+        *phase_total.entry(s.phase.as_str()).or_insert(0.0) += s.duration_secs;
+    }
+
+    let mut slowest: Vec<&Span> = spans.iter().collect();
+    slowest.sort_by(|a, b| b.duration_secs.partial_cmp(&a.duration_secs).unwrap());
+    let slowest_rows: String = slowest
+        .iter()
+        .take(10)
+        .map(|s| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{:.3}s</td></tr>",
+                html_escape(&s.phase),
+                html_escape(&s.name),
+                s.duration_secs
+            )
+        })
+        .collect();
+
+    let mut gantt_rows = String::new();
+    for phase in &phase_order {
+        gantt_rows.push_str(&format!(
+            "<div class=\"phase-label\">{} <span class=\"total\">({:.3}s)</span></div>\n",
+            html_escape(phase),
+            phase_total.get(phase.as_str()).copied().unwrap_or(0.0)
+        ));
+        for s in spans.iter().filter(|s| &s.phase == phase) {
+            let left_pct = s.start_offset_secs / total_elapsed * 100.0;
+            let width_pct = (s.duration_secs / total_elapsed * 100.0).max(0.15);
+            let label = match &s.worker {
+                Some(w) => format!("{} [{}]", s.name, w),
+                None => s.name.clone(),
+            };
+            gantt_rows.push_str(&format!(
+                "<div class=\"bar-row\"><div class=\"bar\" style=\"left:{left_pct:.3}%;width:{width_pct:.3}%\" title=\"{title} — {dur:.3}s\"></div><span class=\"bar-caption\">{caption}</span></div>\n",
+                title = html_escape(&label),
+                dur = s.duration_secs,
+                caption = html_escape(&label),
+            ));
+        }
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html><head><meta charset="utf-8"><title>commit-viz render timing</title>
+<style>
+body {{ background:#12121a; color:#e6e6f0; font-family: ui-monospace, monospace; margin: 24px; }}
+h1 {{ font-size: 18px; }}
+.phase-label {{ margin-top: 18px; font-weight: bold; color: #ffd24d; }}
+.total {{ color: #9696aa; font-weight: normal; }}
+.bar-row {{ position: relative; height: 18px; margin: 2px 0; background: #1d1d26; border-radius: 3px; }}
+.bar {{ position: absolute; top: 0; height: 100%; min-width: 2px; background: #42a5f5; border-radius: 3px; }}
+.bar-caption {{ position: absolute; left: 4px; top: 1px; font-size: 11px; color: #0a0a0f; mix-blend-mode: screen; }}
+table {{ border-collapse: collapse; margin-top: 12px; }}
+td {{ padding: 3px 10px; border-bottom: 1px solid #2a2a36; font-size: 13px; }}
+</style></head>
+<body>
+<h1>commit-viz render timing — total {total:.2}s</h1>
+{gantt}
+<h2>Slowest spans</h2>
+<table><tr><td><b>phase</b></td><td><b>name</b></td><td><b>duration</b></td></tr>{slowest}</table>
+</body></html>
+"#,
+        total = total_elapsed,
+        gantt = gantt_rows,
+        slowest = slowest_rows,
+    )
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
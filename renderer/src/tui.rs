@@ -0,0 +1,182 @@
+//! Interactive terminal statistics browser, built on ratatui: mirrors
+//! `report::render_report`'s panels (summary, category distribution,
+//! top-authors list, release cycle figures) as widgets instead of a PNG,
+//! for a no-image/SSH-friendly way to explore the data. Independent of
+//! `terminal::TerminalRenderer`, which prints a static one-shot ANSI
+//! preview of the change-flow charts rather than running an interactive
+//! event loop.
+
+use crate::data::{CollectedData, Statistics};
+use ratatui::crossterm::event::{self, Event, KeyCode};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::CrosstermBackend;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::Terminal;
+use std::io;
+
+const AUTHORS_PER_PAGE: usize = 10;
+
+/// Which distribution the center panel currently shows, toggled with the
+/// `c`/`a` keys.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum View {
+    Category,
+    Author,
+}
+
+struct AppState {
+    view: View,
+    author_page: usize,
+}
+
+/// Run the interactive browser until the user presses `q`/Esc. Puts the
+/// terminal into raw/alternate-screen mode for the duration and always
+/// restores it on the way out, even if the draw loop returns an error.
+pub fn run(data: &CollectedData) -> io::Result<()> {
+    let Some(stats) = data.statistics.as_ref() else {
+        println!("No statistics available");
+        return Ok(());
+    };
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = AppState { view: View::Category, author_page: 0 };
+    let result = run_loop(&mut terminal, data, stats, &mut app);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn run_loop(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    data: &CollectedData,
+    stats: &Statistics,
+    app: &mut AppState,
+) -> io::Result<()> {
+    let total_author_pages = stats.top_authors.len().div_ceil(AUTHORS_PER_PAGE).max(1);
+
+    loop {
+        terminal.draw(|frame| draw(frame, data, stats, app))?;
+
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Char('c') => app.view = View::Category,
+                KeyCode::Char('a') => app.view = View::Author,
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if app.view == View::Author && app.author_page + 1 < total_author_pages {
+                        app.author_page += 1;
+                    }
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    if app.view == View::Author {
+                        app.author_page = app.author_page.saturating_sub(1);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+fn draw(frame: &mut ratatui::Frame, data: &CollectedData, stats: &Statistics, app: &AppState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(10), Constraint::Length(3)])
+        .split(frame.area());
+
+    let summary = format!(
+        "{}  |  {} commits | {} authors | {} days | {:.1} commits/week  —  c: categories  a: authors  j/k: scroll  q: quit",
+        data.metadata.repo, stats.total_commits, stats.unique_authors, stats.date_span_days, stats.commits_per_week,
+    );
+    frame.render_widget(
+        Paragraph::new(summary).block(Block::default().borders(Borders::ALL).title("commit-viz")),
+        chunks[0],
+    );
+
+    match app.view {
+        View::Category => draw_categories(frame, stats, chunks[1]),
+        View::Author => draw_authors(frame, stats, app, chunks[1]),
+    }
+
+    let rc = &stats.release_cycles;
+    let rc_line = if rc.count >= 2 {
+        format!(
+            "Tagged releases: {} | Mean: {:.1}d | Min: {:.0}d | Max: {:.0}d | StdDev: {:.1}d",
+            rc.count, rc.mean_days, rc.min_days, rc.max_days, rc.stdev_days
+        )
+    } else {
+        "Not enough tagged releases for cycle analysis".to_string()
+    };
+    frame.render_widget(
+        Paragraph::new(rc_line).block(Block::default().borders(Borders::ALL).title("Release Cycle Analysis")),
+        chunks[2],
+    );
+}
+
+fn draw_categories(frame: &mut ratatui::Frame, stats: &Statistics, area: ratatui::layout::Rect) {
+    let categories_ordered = ["feature", "bugfix", "release", "refactor", "docs", "ci", "test", "other"];
+    let max_count =
+        categories_ordered.iter().filter_map(|c| stats.by_category.get(*c)).copied().max().unwrap_or(1).max(1);
+
+    let items: Vec<ListItem> = categories_ordered
+        .iter()
+        .map(|cat| {
+            let count = stats.by_category.get(*cat).copied().unwrap_or(0);
+            let bar_len = ((count as f32 / max_count as f32) * 40.0).round() as usize;
+            let bar: String = "█".repeat(bar_len);
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{cat:<10}"), Style::default().fg(category_color(cat))),
+                Span::raw(format!("{bar} {count}")),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(Block::default().borders(Borders::ALL).title("Commits by Category")), area);
+}
+
+fn draw_authors(frame: &mut ratatui::Frame, stats: &Statistics, app: &AppState, area: ratatui::layout::Rect) {
+    let total_pages = stats.top_authors.len().div_ceil(AUTHORS_PER_PAGE).max(1);
+    let start = app.author_page * AUTHORS_PER_PAGE;
+    let max_commits = stats.top_authors.first().map(|a| a.commits).unwrap_or(1).max(1);
+
+    let items: Vec<ListItem> = stats
+        .top_authors
+        .iter()
+        .skip(start)
+        .take(AUTHORS_PER_PAGE)
+        .map(|a| {
+            let bar_len = ((a.commits as f32 / max_commits as f32) * 30.0).round() as usize;
+            let bar: String = "█".repeat(bar_len);
+            ListItem::new(format!("{:<25} {bar} {}", a.author, a.commits))
+        })
+        .collect();
+
+    let title = format!("Top Authors (page {}/{})", app.author_page + 1, total_pages);
+    frame.render_widget(List::new(items).block(Block::default().borders(Borders::ALL).title(title)), area);
+}
+
+fn category_color(category: &str) -> Color {
+    match category {
+        "feature" => Color::Blue,
+        "bugfix" => Color::Red,
+        "release" => Color::Yellow,
+        "refactor" => Color::Magenta,
+        "docs" => Color::Green,
+        "ci" => Color::Cyan,
+        "test" => Color::LightYellow,
+        _ => Color::Gray,
+    }
+}